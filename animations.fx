@@ -0,0 +1,47 @@
+# Effects of the site, parsed at startup. The syntax is described at the top
+# of src/animations/config.rs.
+#
+# `forward` and `backward` follow the way the visitor moved between tabs, so
+# content slides left going to the next tab and right going back.
+
+banner = sequence(
+    # first we "sweep in" the text from the left, before reversing the effect
+    ping_pong(sweep_in(left_to_right, 10, 0, bg, 750ms quad_in)),
+    # then we coalesce the text back to its original state
+    coalesce(500ms sine_out),
+)
+
+# Content of a tab enters with `<name>.enter` and leaves with `<name>.leave`,
+# the names a tab picks its transition by.
+
+coalesce.enter = coalesce(500ms sine_out)
+coalesce.leave = dissolve(300ms quad_in)
+
+slide.enter = slide_in(forward, 12, 0, bg, 500ms sine_out)
+slide.leave = slide_out(forward, 12, 0, bg, 300ms quad_in)
+
+dissolve.enter = coalesce_from(bg, 500ms quad_out)
+dissolve.leave = dissolve(300ms quad_in)
+
+sweep.enter = sweep_in(forward, 12, 4, bg, 500ms sine_out)
+sweep.leave = sweep_out(forward, 12, 4, bg, 300ms quad_in)
+
+fade_from_bg.enter = fade_from(bg, bg, 500ms sine_out)
+fade_from_bg.leave = fade_to(bg, bg, 300ms quad_in)
+
+glitch.enter = parallel(glitch(15, 500ms), fade_from_fg(bg, 500ms sine_out))
+glitch.leave = parallel(glitch(15, 300ms), fade_to_fg(bg, 300ms quad_in))
+
+# The whole panel fades into the page once the visitor has been idle for a
+# while, leaving the screensaver, and comes back at the next key press.
+
+panel.hide = fade_to(page, page, 1500ms sine_in)
+panel.show = fade_from(page, page, 500ms sine_out)
+
+# A link or tab title the visitor points at or moved the focus to shimmers
+# until the focus moves on.
+
+focus = repeating(sequence(
+    ping_pong(fade_to_fg(rgb(255, 255, 255), 400ms sine_in_out)),
+    sleep(800ms),
+))
@@ -0,0 +1,204 @@
+//! Embeds the Markdown posts in `posts/`, the project stats snapshot in
+//! `stats/snapshot.json` and the daily contributions in
+//! `stats/contributions.json` when there are some, and the photos listed in `photos/gallery.json`
+//! into the binary, draws the banner with the FIGlet fonts in `fonts/` and
+//! checks the effects in `animations.fx`.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+#[path = "tools/front_matter.rs"]
+mod front_matter;
+
+#[path = "tools/stats.rs"]
+mod stats;
+
+#[path = "src/figlet.rs"]
+mod figlet;
+
+#[path = "src/animations/config.rs"]
+#[allow(dead_code)] // Only checked here, the site reads the parsed effects
+mod animations;
+
+/// A photo of the gallery, listed in `photos/gallery.json`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GalleryEntry {
+    file: String, // PNG or JPEG, relative to `photos/`
+    caption: String,
+    caption_tr: Option<String>,
+}
+
+/// Text of the banner, drawn once per bundled font.
+const BANNER_TEXT: &str = "EMIV";
+
+/// Photos are shrunk to fit this many pixels, about what a large pane shows.
+const PHOTO_MAX_SIZE: (u32, u32) = (240, 160);
+
+fn main() {
+    println!("cargo:rerun-if-changed=posts");
+    // A directory, as neither snapshot has to exist
+    println!("cargo:rerun-if-changed=stats");
+    println!("cargo:rerun-if-changed=photos");
+    println!("cargo:rerun-if-changed=fonts");
+    println!("cargo:rerun-if-changed=tools/front_matter.rs");
+    println!("cargo:rerun-if-changed=src/date/calendar.rs");
+    println!("cargo:rerun-if-changed=tools/stats.rs");
+    println!("cargo:rerun-if-changed=src/figlet.rs");
+    println!("cargo:rerun-if-changed=animations.fx");
+    println!("cargo:rerun-if-changed=src/animations/config.rs");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    embed_posts(&out_dir);
+    embed_stats(&out_dir);
+    embed_contributions(&out_dir);
+    embed_photos(&out_dir);
+    embed_banner(&out_dir);
+    check_animations();
+}
+
+fn embed_posts(out_dir: &Path) {
+    let mut out = String::from("&[\n");
+    for post in front_matter::read_posts(Path::new("posts")) {
+        let front_matter = post.front_matter;
+        out.push_str(&format!(
+            "    Post {{ slug: {:?}, title: {:?}, date: {:?}, tags: &{:?}, body: {:?} }},\n",
+            post.slug, front_matter.title, front_matter.date, front_matter.tags, post.markdown
+        ));
+    }
+    out.push_str("]\n");
+
+    fs::write(out_dir.join("posts.rs"), out).expect("failed to write generated posts");
+}
+
+/// Without a snapshot, e.g. before `tools/fetch-stats.sh` first ran, the site
+/// shows no stats.
+fn embed_stats(out_dir: &Path) {
+    let snapshot = match fs::read_to_string("stats/snapshot.json") {
+        Ok(source) => Some(
+            stats::parse_snapshot(&source)
+                .unwrap_or_else(|e| panic!("invalid stats/snapshot.json: {}", e)),
+        ),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => panic!("failed to read stats/snapshot.json: {}", e),
+    };
+
+    let mut out = format!(
+        "pub const STATS_AS_OF: Option<&str> = {:?};\n\n",
+        snapshot.as_ref().map(|snapshot| &snapshot.fetched_at)
+    );
+    out.push_str("pub const CRATE_STATS: &[CrateStats] = &[\n");
+    for (name, stats) in snapshot.iter().flat_map(|snapshot| &snapshot.crates) {
+        out.push_str(&format!(
+            "    CrateStats {{ name: {:?}, downloads: {}, version: {:?} }},\n",
+            name, stats.downloads, stats.version
+        ));
+    }
+    out.push_str("];\n\npub const REPOSITORY_STATS: &[RepositoryStats] = &[\n");
+    for (path, stats) in snapshot.iter().flat_map(|snapshot| &snapshot.repositories) {
+        out.push_str(&format!(
+            "    RepositoryStats {{ path: {:?}, stars: {} }},\n",
+            path, stats.stars
+        ));
+    }
+    out.push_str("];\n");
+
+    fs::write(out_dir.join("stats.rs"), out).expect("failed to write generated stats");
+}
+
+/// Without contributions, which need a GitHub token to fetch, the site shows no heatmap.
+fn embed_contributions(out_dir: &Path) {
+    let contributions = match fs::read_to_string("stats/contributions.json") {
+        Ok(source) => Some(
+            stats::parse_contributions(&source)
+                .unwrap_or_else(|e| panic!("invalid stats/contributions.json: {}", e)),
+        ),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => panic!("failed to read stats/contributions.json: {}", e),
+    };
+
+    let out = format!(
+        "pub const CONTRIBUTIONS_FROM: Option<&str> = {:?};\n\npub const CONTRIBUTION_COUNTS: &[u16] = &{:?};\n",
+        contributions.as_ref().map(|contributions| &contributions.from),
+        contributions.as_ref().map_or(&[][..], |contributions| &contributions.counts)
+    );
+    fs::write(out_dir.join("contributions.rs"), out)
+        .expect("failed to write generated contributions");
+}
+
+/// Decodes every photo once at build time, so the site ships raw RGB pixels
+/// instead of an image decoder.
+fn embed_photos(out_dir: &Path) {
+    let source =
+        fs::read_to_string("photos/gallery.json").expect("failed to read photos/gallery.json");
+    let gallery: Vec<GalleryEntry> = serde_json::from_str(&source)
+        .unwrap_or_else(|e| panic!("invalid photos/gallery.json: {}", e));
+
+    let mut out = String::from("&[\n");
+    for (i, entry) in gallery.iter().enumerate() {
+        let path = Path::new("photos").join(&entry.file);
+        let photo = image::open(&path)
+            .unwrap_or_else(|e| panic!("invalid photo {}: {}", path.display(), e));
+        let (max_width, max_height) = PHOTO_MAX_SIZE;
+        let photo = if photo.width() > max_width || photo.height() > max_height {
+            photo.resize(max_width, max_height, image::imageops::FilterType::Triangle)
+        } else {
+            photo
+        }
+        .to_rgb8();
+
+        let pixels = format!("photo-{}.rgb", i);
+        fs::write(out_dir.join(&pixels), photo.as_raw()).expect("failed to write photo pixels");
+        let caption = match &entry.caption_tr {
+            Some(tr) => format!("Localized::en({:?}).tr({:?})", entry.caption, tr),
+            None => format!("Localized::en({:?})", entry.caption),
+        };
+        out.push_str(&format!(
+            "    Photo {{ caption: {}, width: {}, height: {}, pixels: include_bytes!(concat!(env!(\"OUT_DIR\"), \"/{}\")) }},\n",
+            caption,
+            photo.width(),
+            photo.height(),
+            pixels
+        ));
+    }
+    out.push_str("]\n");
+
+    fs::write(out_dir.join("photos.rs"), out).expect("failed to write generated photos");
+}
+
+/// The owner's hand-shaded banner stays the widest variant, the FIGlet drawings
+/// take over on narrower screens.
+fn embed_banner(out_dir: &Path) {
+    let shaded = fs::read_to_string("fonts/banner.txt").expect("failed to read fonts/banner.txt");
+    let mut variants = figlet::FONTS
+        .iter()
+        .map(|(name, source)| {
+            let font = figlet::Font::parse(source)
+                .unwrap_or_else(|error| panic!("invalid font fonts/{}.flf: {}", name, error));
+            let mut rows = font.render(BANNER_TEXT);
+            // Rows below the baseline stay empty for capitals
+            while rows.last().is_some_and(|row| row.is_empty()) {
+                rows.pop();
+            }
+            format!("\n{}\n", rows.join("\n"))
+        })
+        .collect::<Vec<_>>();
+    // Widest first, the order `AsciiArt` expects
+    variants.sort_by_key(|art| std::cmp::Reverse(art.lines().map(|row| row.chars().count()).max()));
+    variants.insert(0, format!("\n{}", shaded));
+
+    fs::write(out_dir.join("banner.rs"), format!("&{:?}\n", variants))
+        .expect("failed to write generated banner");
+}
+
+/// Fails the build on effects the site could not parse at startup.
+fn check_animations() {
+    let source = fs::read_to_string("animations.fx").expect("failed to read animations.fx");
+    if let Err(error) = animations::parse(&source) {
+        panic!("invalid animations.fx:{}", error);
+    }
+}
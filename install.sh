@@ -28,3 +28,7 @@ fi
 # Build project with trunk
 echo "Building project with trunk"
 trunk build
+
+# Feeds are generated natively, next to the site
+echo "Generating feeds"
+cargo run --release -p feeds -- dist
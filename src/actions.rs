@@ -0,0 +1,207 @@
+use ratzilla::utils::open_url;
+
+use crate::app::AppState;
+use crate::data::{CONTACT, LINKS};
+use crate::i18n::{Locale, Message};
+use crate::resume::resume_text;
+use crate::tabs::TabContext;
+use crate::web::download_file;
+
+/// Everything the visitor can do, from the keyboard or the command palette.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    NextTab,
+    PreviousTab,
+    GoToTab(usize),
+    FocusNextPane,
+    FocusPreviousPane,
+    ScrollDown,
+    ScrollUp,
+    HalfPageDown,
+    HalfPageUp,
+    PageDown,
+    PageUp,
+    ScrollToTop,
+    ScrollToBottom,
+    Activate,
+    Back,
+    ToggleTimeline,
+    PreviousEntry,
+    NextEntry,
+    CycleRenderMode,
+    Search,
+    NextMatch,
+    PreviousMatch,
+    ClearSearch,
+    OpenPalette,
+    ToggleHelp,
+    CycleKeymap,
+    ToggleTheme,
+    CycleLocale,
+    ToggleAnimations,
+    FocusNextLink,
+    OpenLink(usize),
+    DownloadResume,
+    DownloadContact,
+}
+
+impl Action {
+    /// Every action in the order the command palette lists them.
+    pub fn all(tab_count: usize) -> Vec<Action> {
+        let mut actions: Vec<Action> = (0..tab_count).map(Action::GoToTab).collect();
+        actions.extend([
+            Action::NextTab,
+            Action::PreviousTab,
+            Action::FocusNextPane,
+            Action::FocusPreviousPane,
+            Action::ScrollDown,
+            Action::ScrollUp,
+            Action::HalfPageDown,
+            Action::HalfPageUp,
+            Action::PageDown,
+            Action::PageUp,
+            Action::ScrollToTop,
+            Action::ScrollToBottom,
+            Action::Activate,
+            Action::Back,
+            Action::ToggleTimeline,
+            Action::PreviousEntry,
+            Action::NextEntry,
+            Action::CycleRenderMode,
+            Action::Search,
+            Action::NextMatch,
+            Action::PreviousMatch,
+            Action::ClearSearch,
+            Action::ToggleHelp,
+            Action::CycleKeymap,
+            Action::ToggleTheme,
+            Action::CycleLocale,
+            Action::ToggleAnimations,
+            Action::FocusNextLink,
+        ]);
+        actions.extend((0..LINKS.len()).map(Action::OpenLink));
+        actions.extend([Action::DownloadResume, Action::DownloadContact]);
+        actions
+    }
+
+    pub fn label(&self, tab_titles: &[&str], locale: Locale) -> String {
+        let message = match self {
+            Action::NextTab => Message::NextTab,
+            Action::PreviousTab => Message::PreviousTab,
+            Action::GoToTab(_) => Message::GoToTab,
+            Action::FocusNextPane => Message::FocusNextPane,
+            Action::FocusPreviousPane => Message::FocusPreviousPane,
+            Action::ScrollDown => Message::ScrollDown,
+            Action::ScrollUp => Message::ScrollUp,
+            Action::HalfPageDown => Message::HalfPageDown,
+            Action::HalfPageUp => Message::HalfPageUp,
+            Action::PageDown => Message::PageDown,
+            Action::PageUp => Message::PageUp,
+            Action::ScrollToTop => Message::ScrollToTop,
+            Action::ScrollToBottom => Message::ScrollToBottom,
+            Action::Activate => Message::Activate,
+            Action::Back => Message::Back,
+            Action::ToggleTimeline => Message::ToggleTimeline,
+            Action::PreviousEntry => Message::PreviousEntry,
+            Action::NextEntry => Message::NextEntry,
+            Action::CycleRenderMode => Message::CycleRenderMode,
+            Action::Search => Message::Search,
+            Action::NextMatch => Message::NextMatch,
+            Action::PreviousMatch => Message::PreviousMatch,
+            Action::ClearSearch => Message::ClearSearch,
+            Action::OpenPalette => Message::OpenPalette,
+            Action::ToggleHelp => Message::ToggleHelp,
+            Action::CycleKeymap => Message::CycleKeymap,
+            Action::ToggleTheme => Message::ToggleTheme,
+            Action::CycleLocale => Message::CycleLocale,
+            Action::ToggleAnimations => Message::ToggleAnimations,
+            Action::FocusNextLink => Message::FocusNextLink,
+            Action::OpenLink(_) => Message::OpenLink,
+            Action::DownloadResume => Message::DownloadResume,
+            Action::DownloadContact => Message::DownloadContact,
+        };
+        match self {
+            Action::GoToTab(tab) => locale.format(message, tab_titles[*tab]),
+            Action::OpenLink(link) => locale.format(message, LINKS[*link].0),
+            _ => locale.message(message).to_string(),
+        }
+    }
+
+    /// Every action the command palette offers on the current tab.
+    pub fn available(state: &AppState) -> Vec<Action> {
+        Action::all(state.tabs.len())
+            .into_iter()
+            .filter(|action| *action != Action::OpenPalette && action.is_available(state))
+            .collect()
+    }
+
+    /// Whether the action makes sense on the current tab.
+    pub fn is_available(&self, state: &AppState) -> bool {
+        match self {
+            Action::GoToTab(tab) => *tab < state.tabs.len(),
+            Action::DownloadContact => state.tabs[state.tab_index].title() == Message::Contact,
+            _ => true,
+        }
+    }
+
+    pub fn run(self, state: &mut AppState) {
+        // A focused link takes Enter before the tab does
+        if let (Action::Activate, Some(link)) = (self, state.focused_link) {
+            let _ = open_url(LINKS[link].1, true);
+            return;
+        }
+        // The current tab gets the first say, e.g. to move a selection
+        let (tab, focused_pane) = state.focused();
+        let mut cx = TabContext {
+            focused_pane,
+            scroll: &mut state.scroll_positions[tab],
+            selection: &mut state.selections[tab],
+        };
+        if state.tabs[tab].handle_action(self, &mut cx) {
+            // The tab may have opened or closed a view, moving what the hits point at
+            state.search.refresh(&state.tabs, state.locale);
+            return;
+        }
+        match self {
+            Action::NextTab => state.next_tab(),
+            Action::PreviousTab => state.previous_tab(),
+            Action::GoToTab(tab) => state.select_tab(tab),
+            Action::FocusNextPane => state.focus_next_pane(),
+            Action::FocusPreviousPane => state.focus_previous_pane(),
+            Action::ScrollDown => state.scroll_down(),
+            Action::ScrollUp => state.scroll_up(),
+            Action::HalfPageDown => state.half_page_down(),
+            Action::HalfPageUp => state.half_page_up(),
+            Action::PageDown => state.page_down(),
+            Action::PageUp => state.page_up(),
+            Action::ScrollToTop => state.scroll_to_top(),
+            Action::ScrollToBottom => state.scroll_to_bottom(),
+            Action::Activate
+            | Action::Back
+            | Action::ToggleTimeline
+            | Action::PreviousEntry
+            | Action::NextEntry
+            | Action::CycleRenderMode => {}
+            Action::Search => state.search.open(),
+            Action::NextMatch => state.search.next(),
+            Action::PreviousMatch => state.search.previous(),
+            Action::ClearSearch => state.search.close(),
+            Action::OpenPalette => state.palette.open(),
+            Action::ToggleHelp => state.help_open = !state.help_open,
+            Action::CycleKeymap => state.cycle_keymap(),
+            Action::ToggleTheme => state.toggle_theme(),
+            Action::CycleLocale => state.cycle_locale(),
+            Action::ToggleAnimations => state.animations_enabled = !state.animations_enabled,
+            Action::FocusNextLink => state.focus_next_link(),
+            Action::OpenLink(link) => {
+                let _ = open_url(LINKS[link].1, true);
+            }
+            Action::DownloadResume => {
+                download_file("emirhan-tala-resume.txt", "text/plain", &resume_text());
+            }
+            Action::DownloadContact => {
+                download_file("contact.vcf", "text/vcard", &CONTACT.to_vcard());
+            }
+        }
+    }
+}
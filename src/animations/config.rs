@@ -0,0 +1,658 @@
+//! The effects of the site, written in `animations.fx`:
+//!
+//! ```text
+//! # Comments run to the end of the line
+//! banner = sequence(
+//!     ping_pong(sweep_in(left_to_right, 10, 0, bg, 750ms quad_in)),
+//!     coalesce(500ms sine_out),
+//! )
+//! ```
+//!
+//! Every definition names an effect the site looks up, see [`EFFECT_NAMES`].
+//! An effect is a call of one of the tachyonfx effects below, whose arguments
+//! are effects, whole numbers, timers (`500ms`, optionally followed by an
+//! easing such as `sine_out`), motions and colors:
+//!
+//! - `sequence(effect, ...)`, `parallel(effect, ...)`, `ping_pong(effect)`,
+//!   `repeating(effect)`, `delay(timer, effect)`, `sleep(timer)`
+//! - `coalesce(timer)`, `dissolve(timer)`, `coalesce_from(color, timer)`,
+//!   `dissolve_to(color, timer)`
+//! - `sweep_in`, `sweep_out`, `slide_in`, `slide_out`, each taking
+//!   `(motion, gradient length, randomness, color, timer)`
+//! - `fade_from(fg, bg, timer)`, `fade_to(fg, bg, timer)`,
+//!   `fade_from_fg(color, timer)`, `fade_to_fg(color, timer)`
+//! - `glitch(percent of cells, timer)`
+//!
+//! Motions are `left_to_right`, `right_to_left`, `up_to_down`, `down_to_up`,
+//! and `forward` or `backward` for the way the visitor moved between tabs.
+//! Colors are `bg`, the background of the theme in use when the effect starts,
+//! `page`, the background of the page around the panel, or `rgb(16, 24, 39)`.
+//!
+//! Shared by the build script, which rejects an invalid file, and the site,
+//! which turns the definitions into effects at startup.
+
+use std::collections::HashMap;
+
+/// Effects the site looks up, each defined exactly once.
+pub const EFFECT_NAMES: &[&str] = &[
+    "banner",
+    "coalesce.enter",
+    "coalesce.leave",
+    "slide.enter",
+    "slide.leave",
+    "dissolve.enter",
+    "dissolve.leave",
+    "sweep.enter",
+    "sweep.leave",
+    "fade_from_bg.enter",
+    "fade_from_bg.leave",
+    "glitch.enter",
+    "glitch.leave",
+    "panel.hide",
+    "panel.show",
+    "focus",
+];
+
+pub enum EffectSpec {
+    Sequence(Vec<EffectSpec>),
+    Parallel(Vec<EffectSpec>),
+    PingPong(Box<EffectSpec>),
+    Repeating(Box<EffectSpec>), // Forever
+    Delay(Timer, Box<EffectSpec>),
+    Sleep(Timer),
+    Coalesce(Timer),
+    CoalesceFrom(ColorSpec, Timer),
+    Dissolve(Timer),
+    DissolveTo(ColorSpec, Timer),
+    SweepIn(Sweep),
+    SweepOut(Sweep),
+    SlideIn(Sweep),
+    SlideOut(Sweep),
+    FadeFrom(ColorSpec, ColorSpec, Timer),
+    FadeTo(ColorSpec, ColorSpec, Timer),
+    FadeFromFg(ColorSpec, Timer),
+    FadeToFg(ColorSpec, Timer),
+    Glitch(u16, Timer), // Percent of cells
+}
+
+/// Arguments shared by sweeps and slides.
+pub struct Sweep {
+    pub motion: MotionSpec,
+    pub gradient_length: u16,
+    pub randomness: u16,
+    pub color: ColorSpec,
+    pub timer: Timer,
+}
+
+#[derive(Clone, Copy)]
+pub struct Timer {
+    pub ms: u32,
+    pub easing: Easing,
+}
+
+#[derive(Clone, Copy)]
+pub enum MotionSpec {
+    LeftToRight,
+    RightToLeft,
+    UpToDown,
+    DownToUp,
+    Forward,
+    Backward,
+}
+
+#[derive(Clone, Copy)]
+pub enum ColorSpec {
+    Background,
+    Page,
+    Rgb(u8, u8, u8),
+}
+
+#[derive(Clone, Copy, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+}
+
+const EASINGS: &[(&str, Easing)] = &[
+    ("linear", Easing::Linear),
+    ("quad_in", Easing::QuadIn),
+    ("quad_out", Easing::QuadOut),
+    ("quad_in_out", Easing::QuadInOut),
+    ("cubic_in", Easing::CubicIn),
+    ("cubic_out", Easing::CubicOut),
+    ("cubic_in_out", Easing::CubicInOut),
+    ("quart_in", Easing::QuartIn),
+    ("quart_out", Easing::QuartOut),
+    ("quart_in_out", Easing::QuartInOut),
+    ("quint_in", Easing::QuintIn),
+    ("quint_out", Easing::QuintOut),
+    ("quint_in_out", Easing::QuintInOut),
+    ("sine_in", Easing::SineIn),
+    ("sine_out", Easing::SineOut),
+    ("sine_in_out", Easing::SineInOut),
+    ("circ_in", Easing::CircIn),
+    ("circ_out", Easing::CircOut),
+    ("circ_in_out", Easing::CircInOut),
+    ("expo_in", Easing::ExpoIn),
+    ("expo_out", Easing::ExpoOut),
+    ("expo_in_out", Easing::ExpoInOut),
+    ("elastic_in", Easing::ElasticIn),
+    ("elastic_out", Easing::ElasticOut),
+    ("elastic_in_out", Easing::ElasticInOut),
+    ("bounce_in", Easing::BounceIn),
+    ("bounce_out", Easing::BounceOut),
+    ("bounce_in_out", Easing::BounceInOut),
+    ("back_in", Easing::BackIn),
+    ("back_out", Easing::BackOut),
+    ("back_in_out", Easing::BackInOut),
+];
+
+const MOTIONS: &[(&str, MotionSpec)] = &[
+    ("left_to_right", MotionSpec::LeftToRight),
+    ("right_to_left", MotionSpec::RightToLeft),
+    ("up_to_down", MotionSpec::UpToDown),
+    ("down_to_up", MotionSpec::DownToUp),
+    ("forward", MotionSpec::Forward),
+    ("backward", MotionSpec::Backward),
+];
+
+/// Line and column, both counted from 1.
+#[derive(Clone, Copy)]
+struct Position(usize, usize);
+
+enum Token {
+    Word(String), // Names, which may contain dots
+    Number(u32),
+    Duration(u32), // Milliseconds
+    Open,
+    Close,
+    Comma,
+    Equals,
+}
+
+/// An argument before it is checked against what the effect expects.
+enum Value {
+    Call(String, Vec<(Value, Position)>),
+    Word(String),
+    Number(u32),
+    Timer(Timer),
+}
+
+/// Every definition of `source` by name, checked against [`EFFECT_NAMES`].
+pub fn parse(source: &str) -> Result<HashMap<String, EffectSpec>, String> {
+    let tokens = tokenize(source)?;
+    let mut parser = Parser { tokens, next: 0 };
+    let mut effects = HashMap::new();
+    while let Some(&(_, position)) = parser.tokens.get(parser.next) {
+        let name = match parser.advance() {
+            Some(Token::Word(name)) => name.clone(),
+            _ => return Err(error(position, "expected the name of an effect")),
+        };
+        if !EFFECT_NAMES.contains(&name.as_str()) {
+            return Err(error(
+                position,
+                &format!(
+                    "unknown name `{}`, expected one of {}",
+                    name,
+                    EFFECT_NAMES.join(", ")
+                ),
+            ));
+        }
+        if effects.contains_key(&name) {
+            return Err(error(position, &format!("`{}` is defined twice", name)));
+        }
+        parser.expect(|token| matches!(token, Token::Equals), "`=`")?;
+        let (value, position) = parser.value()?;
+        effects.insert(name, effect(value, position)?);
+    }
+    if let Some(missing) = EFFECT_NAMES
+        .iter()
+        .find(|name| !effects.contains_key(**name))
+    {
+        return Err(format!("`{}` is not defined", missing));
+    }
+    Ok(effects)
+}
+
+fn error(Position(line, column): Position, message: &str) -> String {
+    format!("{}:{}: {}", line, column, message)
+}
+
+fn tokenize(source: &str) -> Result<Vec<(Token, Position)>, String> {
+    let mut tokens = Vec::new();
+    for (line_index, line) in source.lines().enumerate() {
+        let mut chars = line.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            let position = Position(line_index + 1, line[..start].chars().count() + 1);
+            // End of a run of characters matching `f` from the current one on
+            let mut run_end = |f: fn(char) -> bool| {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, next)) = chars.peek() {
+                    if !f(next) {
+                        break;
+                    }
+                    end = i + next.len_utf8();
+                    chars.next();
+                }
+                end
+            };
+            let token = match c {
+                '#' => break,
+                ' ' | '\t' => continue,
+                '(' => Token::Open,
+                ')' => Token::Close,
+                ',' => Token::Comma,
+                '=' => Token::Equals,
+                '0'..='9' => {
+                    let end = run_end(|c| c.is_ascii_alphanumeric());
+                    let text = &line[start..end];
+                    let (digits, duration) = match text.strip_suffix("ms") {
+                        Some(digits) => (digits, true),
+                        None => (text, false),
+                    };
+                    let number = digits
+                        .parse()
+                        .map_err(|_| error(position, &format!("invalid number `{}`", text)))?;
+                    if duration {
+                        Token::Duration(number)
+                    } else {
+                        Token::Number(number)
+                    }
+                }
+                c if c.is_ascii_alphabetic() || c == '_' => {
+                    let end = run_end(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+                    Token::Word(line[start..end].to_string())
+                }
+                c => return Err(error(position, &format!("unexpected `{}`", c))),
+            };
+            tokens.push((token, position));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, Position)>,
+    next: usize,
+}
+
+impl Parser {
+    fn advance(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.next).map(|(token, _)| token);
+        self.next += 1;
+        token
+    }
+
+    fn position(&self) -> Position {
+        match self.tokens.get(self.next).or(self.tokens.last()) {
+            Some(&(_, position)) => position,
+            None => Position(1, 1),
+        }
+    }
+
+    fn expect(&mut self, f: fn(&Token) -> bool, expected: &str) -> Result<(), String> {
+        let position = self.position();
+        match self.advance() {
+            Some(token) if f(token) => Ok(()),
+            Some(_) => Err(error(position, &format!("expected {}", expected))),
+            None => Err(error(
+                position,
+                &format!("expected {} at the end", expected),
+            )),
+        }
+    }
+
+    fn value(&mut self) -> Result<(Value, Position), String> {
+        let position = self.position();
+        let value = match self.advance() {
+            Some(Token::Word(word)) => {
+                let word = word.clone();
+                if !matches!(self.tokens.get(self.next), Some((Token::Open, _))) {
+                    return Ok((Value::Word(word), position));
+                }
+                self.next += 1;
+                let mut arguments = Vec::new();
+                // Arguments up to the closing parenthesis, a trailing comma allowed
+                while !matches!(self.tokens.get(self.next), Some((Token::Close, _))) {
+                    arguments.push(self.value()?);
+                    if matches!(self.tokens.get(self.next), Some((Token::Comma, _))) {
+                        self.next += 1;
+                    } else {
+                        break;
+                    }
+                }
+                self.expect(|token| matches!(token, Token::Close), "`)`")?;
+                Value::Call(word, arguments)
+            }
+            Some(&Token::Number(number)) => Value::Number(number),
+            Some(&Token::Duration(ms)) => {
+                let mut easing = Easing::default();
+                // An easing right after the duration belongs to the timer
+                if let Some((Token::Word(word), easing_position)) = self.tokens.get(self.next) {
+                    if !matches!(self.tokens.get(self.next + 1), Some((Token::Open, _))) {
+                        easing = EASINGS
+                            .iter()
+                            .find(|(name, _)| name == word)
+                            .map(|(_, easing)| *easing)
+                            .ok_or_else(|| {
+                                error(*easing_position, &format!("unknown easing `{}`", word))
+                            })?;
+                        self.next += 1;
+                    }
+                }
+                Value::Timer(Timer { ms, easing })
+            }
+            Some(_) => return Err(error(position, "expected an effect or a value")),
+            None => return Err(error(position, "expected an effect or a value at the end")),
+        };
+        Ok((value, position))
+    }
+}
+
+/// The arguments of a call, when there are exactly `N` of them.
+fn arguments<const N: usize>(
+    name: &str,
+    arguments: Vec<(Value, Position)>,
+    position: Position,
+) -> Result<[(Value, Position); N], String> {
+    let count = arguments.len();
+    arguments.try_into().map_err(|_| {
+        error(
+            position,
+            &format!("`{}` takes {} arguments, not {}", name, N, count),
+        )
+    })
+}
+
+fn effect(value: Value, position: Position) -> Result<EffectSpec, String> {
+    let Value::Call(name, args) = value else {
+        return Err(error(position, "expected an effect"));
+    };
+    let effects = |args: Vec<(Value, Position)>| -> Result<Vec<EffectSpec>, String> {
+        if args.is_empty() {
+            return Err(error(
+                position,
+                &format!("`{}` needs at least one effect", name),
+            ));
+        }
+        args.into_iter()
+            .map(|(value, position)| effect(value, position))
+            .collect()
+    };
+    let sweep = |args| -> Result<Sweep, String> {
+        let [motion, gradient_length, randomness, color, timer] = arguments(&name, args, position)?;
+        Ok(Sweep {
+            motion: motion_spec(motion)?,
+            gradient_length: number(gradient_length)?,
+            randomness: number(randomness)?,
+            color: color_spec(color)?,
+            timer: timer_spec(timer)?,
+        })
+    };
+    Ok(match name.as_str() {
+        "sequence" => EffectSpec::Sequence(effects(args)?),
+        "parallel" => EffectSpec::Parallel(effects(args)?),
+        "ping_pong" => {
+            let [inner] = arguments(&name, args, position)?;
+            EffectSpec::PingPong(Box::new(effect(inner.0, inner.1)?))
+        }
+        "repeating" => {
+            let [inner] = arguments(&name, args, position)?;
+            EffectSpec::Repeating(Box::new(effect(inner.0, inner.1)?))
+        }
+        "delay" => {
+            let [timer, inner] = arguments(&name, args, position)?;
+            EffectSpec::Delay(timer_spec(timer)?, Box::new(effect(inner.0, inner.1)?))
+        }
+        "sleep" => {
+            let [timer] = arguments(&name, args, position)?;
+            EffectSpec::Sleep(timer_spec(timer)?)
+        }
+        "coalesce" | "dissolve" => {
+            let [timer] = arguments(&name, args, position)?;
+            let timer = timer_spec(timer)?;
+            match name.as_str() {
+                "coalesce" => EffectSpec::Coalesce(timer),
+                _ => EffectSpec::Dissolve(timer),
+            }
+        }
+        "coalesce_from" | "dissolve_to" | "fade_from_fg" | "fade_to_fg" => {
+            let [color, timer] = arguments(&name, args, position)?;
+            let (color, timer) = (color_spec(color)?, timer_spec(timer)?);
+            match name.as_str() {
+                "coalesce_from" => EffectSpec::CoalesceFrom(color, timer),
+                "dissolve_to" => EffectSpec::DissolveTo(color, timer),
+                "fade_from_fg" => EffectSpec::FadeFromFg(color, timer),
+                _ => EffectSpec::FadeToFg(color, timer),
+            }
+        }
+        "fade_from" | "fade_to" => {
+            let [fg, bg, timer] = arguments(&name, args, position)?;
+            let (fg, bg, timer) = (color_spec(fg)?, color_spec(bg)?, timer_spec(timer)?);
+            match name.as_str() {
+                "fade_from" => EffectSpec::FadeFrom(fg, bg, timer),
+                _ => EffectSpec::FadeTo(fg, bg, timer),
+            }
+        }
+        "sweep_in" => EffectSpec::SweepIn(sweep(args)?),
+        "sweep_out" => EffectSpec::SweepOut(sweep(args)?),
+        "slide_in" => EffectSpec::SlideIn(sweep(args)?),
+        "slide_out" => EffectSpec::SlideOut(sweep(args)?),
+        "glitch" => {
+            let [percent, timer] = arguments(&name, args, position)?;
+            let percent_position = percent.1;
+            let percent = number(percent)?;
+            if percent > 100 {
+                return Err(error(percent_position, "expected a percentage up to 100"));
+            }
+            EffectSpec::Glitch(percent, timer_spec(timer)?)
+        }
+        _ => return Err(error(position, &format!("unknown effect `{}`", name))),
+    })
+}
+
+fn number((value, position): (Value, Position)) -> Result<u16, String> {
+    match value {
+        Value::Number(number) => {
+            u16::try_from(number).map_err(|_| error(position, &format!("{} is too large", number)))
+        }
+        _ => Err(error(position, "expected a whole number")),
+    }
+}
+
+fn timer_spec((value, position): (Value, Position)) -> Result<Timer, String> {
+    match value {
+        Value::Timer(timer) => Ok(timer),
+        _ => Err(error(
+            position,
+            "expected a duration such as `500ms sine_out`",
+        )),
+    }
+}
+
+fn motion_spec((value, position): (Value, Position)) -> Result<MotionSpec, String> {
+    let word = match value {
+        Value::Word(word) => word,
+        _ => return Err(error(position, "expected a motion")),
+    };
+    MOTIONS
+        .iter()
+        .find(|(name, _)| *name == word)
+        .map(|(_, motion)| *motion)
+        .ok_or_else(|| error(position, &format!("unknown motion `{}`", word)))
+}
+
+fn color_spec((value, position): (Value, Position)) -> Result<ColorSpec, String> {
+    match value {
+        Value::Word(word) if word == "bg" => Ok(ColorSpec::Background),
+        Value::Word(word) if word == "page" => Ok(ColorSpec::Page),
+        Value::Call(name, args) if name == "rgb" => {
+            let [r, g, b] = arguments(&name, args, position)?;
+            let channel = |channel: (Value, Position)| {
+                let position = channel.1;
+                u8::try_from(number(channel)?)
+                    .map_err(|_| error(position, "expected a channel from 0 to 255"))
+            };
+            Ok(ColorSpec::Rgb(channel(r)?, channel(g)?, channel(b)?))
+        }
+        _ => Err(error(position, "expected `bg`, `page` or `rgb(r, g, b)`")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every required effect defined as a short coalesce, except those in `overrides`.
+    fn source(overrides: &[(&str, &str)]) -> String {
+        EFFECT_NAMES
+            .iter()
+            .map(|name| {
+                let effect = overrides
+                    .iter()
+                    .find(|(overridden, _)| overridden == name)
+                    .map_or("coalesce(100ms)", |(_, effect)| effect);
+                format!("{} = {}\n", name, effect)
+            })
+            .collect()
+    }
+
+    /// The error of `banner` defined as `effect`.
+    fn banner_error(effect: &str) -> String {
+        match parse(&source(&[("banner", effect)])) {
+            Ok(_) => panic!("`{}` parsed", effect),
+            Err(error) => error,
+        }
+    }
+
+    #[test]
+    fn parses_the_site_effects() {
+        let effects = parse(include_str!("../../animations.fx")).unwrap();
+        assert_eq!(effects.len(), EFFECT_NAMES.len());
+    }
+
+    #[test]
+    fn parses_every_kind_of_argument() {
+        let banner = "sequence(sweep_in(forward, 10, 0, bg, 750ms quad_in), \
+                      fade_to(page, rgb(1, 2, 3), 5ms), glitch(15, 300ms))";
+        let effects = parse(&source(&[("banner", banner)])).unwrap();
+        let EffectSpec::Sequence(steps) = &effects["banner"] else {
+            panic!("not a sequence");
+        };
+        assert!(matches!(
+            steps[..],
+            [
+                EffectSpec::SweepIn(Sweep {
+                    motion: MotionSpec::Forward,
+                    gradient_length: 10,
+                    color: ColorSpec::Background,
+                    timer: Timer {
+                        ms: 750,
+                        easing: Easing::QuadIn
+                    },
+                    ..
+                }),
+                EffectSpec::FadeTo(
+                    ColorSpec::Page,
+                    ColorSpec::Rgb(1, 2, 3),
+                    Timer { ms: 5, .. }
+                ),
+                EffectSpec::Glitch(15, Timer { ms: 300, .. }),
+            ]
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_effects() {
+        assert_eq!(
+            banner_error("sparkle(100ms)"),
+            "1:10: unknown effect `sparkle`"
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_argument_counts() {
+        assert_eq!(
+            banner_error("coalesce(100ms, 200ms)"),
+            "1:10: `coalesce` takes 1 arguments, not 2"
+        );
+        assert_eq!(
+            banner_error("fade_to(bg, 100ms)"),
+            "1:10: `fade_to` takes 3 arguments, not 2"
+        );
+        assert_eq!(
+            banner_error("sequence()"),
+            "1:10: `sequence` needs at least one effect"
+        );
+    }
+
+    #[test]
+    fn rejects_bad_durations_and_easings() {
+        assert_eq!(
+            banner_error("coalesce(100)"),
+            "1:19: expected a duration such as `500ms sine_out`"
+        );
+        assert_eq!(
+            banner_error("coalesce(100ms wobbly)"),
+            "1:25: unknown easing `wobbly`"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_colors() {
+        assert_eq!(
+            banner_error("fade_to_fg(red, 100ms)"),
+            "1:21: expected `bg`, `page` or `rgb(r, g, b)`"
+        );
+        assert_eq!(
+            banner_error("fade_to_fg(rgb(1, 2, 300), 100ms)"),
+            "1:31: expected a channel from 0 to 255"
+        );
+    }
+
+    #[test]
+    fn rejects_missing_and_unknown_names() {
+        let source = source(&[]).replace("focus = coalesce(100ms)\n", "");
+        assert_eq!(parse(&source).err().unwrap(), "`focus` is not defined");
+
+        let source = format!("{}sparkle = coalesce(100ms)\n", self::source(&[]));
+        let error = parse(&source).err().unwrap();
+        assert!(error.contains("unknown name `sparkle`"), "{}", error);
+
+        let source = format!("{}banner = coalesce(100ms)\n", self::source(&[]));
+        assert!(parse(&source)
+            .err()
+            .unwrap()
+            .contains("`banner` is defined twice"));
+    }
+}
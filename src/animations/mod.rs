@@ -0,0 +1,397 @@
+use crate::ui::{Theme, PAGE_COLOR};
+use ratzilla::ratatui::buffer::{Buffer, Cell};
+use ratzilla::ratatui::layout::Rect;
+use ratzilla::ratatui::style::{Color, Style};
+use ratzilla::ratatui::Frame;
+use std::collections::HashMap;
+use tachyonfx::{
+    fx, Duration, Effect, EffectRenderer, EffectTimer, Interpolation, IntoEffect, Motion, Shader,
+};
+
+mod config;
+use config::*;
+
+/// Which way the visitor moved between tabs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Direction {
+    #[default]
+    Next,
+    Previous,
+}
+
+impl Direction {
+    /// Content moves against the direction, like pages turned forward or back.
+    fn motion(self) -> Motion {
+        match self {
+            Direction::Next => Motion::RightToLeft,
+            Direction::Previous => Motion::LeftToRight,
+        }
+    }
+
+    fn reversed(self) -> Direction {
+        match self {
+            Direction::Next => Direction::Previous,
+            Direction::Previous => Direction::Next,
+        }
+    }
+}
+
+/// Named effects the content of a tab enters and leaves with, each defined in
+/// `animations.fx` as `<name>.enter` and `<name>.leave`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TransitionEffect {
+    #[default]
+    Coalesce,
+    Slide,
+    Dissolve,
+    Sweep,
+    FadeFromBg,
+    Glitch,
+}
+
+impl TransitionEffect {
+    fn name(self) -> &'static str {
+        match self {
+            TransitionEffect::Coalesce => "coalesce",
+            TransitionEffect::Slide => "slide",
+            TransitionEffect::Dissolve => "dissolve",
+            TransitionEffect::Sweep => "sweep",
+            TransitionEffect::FadeFromBg => "fade_from_bg",
+            TransitionEffect::Glitch => "glitch",
+        }
+    }
+}
+
+/// The effects of `animations.fx`, parsed once at startup.
+pub struct Effects {
+    specs: HashMap<String, EffectSpec>,
+}
+
+impl Effects {
+    pub fn load() -> Self {
+        // The build script already rejected an invalid file
+        let specs = config::parse(include_str!("../../animations.fx"))
+            .unwrap_or_else(|error| panic!("invalid animations.fx: {}", error));
+        Self { specs }
+    }
+
+    /// A fresh run of the effect defined as `name`, one of `EFFECT_NAMES`, moving
+    /// `direction` where it says `forward` or `backward` and fading to the
+    /// background of `theme` where it says `bg`.
+    fn create(&self, name: &str, direction: Direction, theme: &Theme) -> Effect {
+        build(&self.specs[name], direction, theme)
+    }
+
+    pub fn banner(&self, theme: &Theme) -> Effect {
+        self.create("banner", Direction::default(), theme)
+    }
+
+    /// Effect fading the panel into the page when the screensaver starts.
+    pub fn hide_panel(&self, theme: &Theme) -> Effect {
+        self.create("panel.hide", Direction::default(), theme)
+    }
+
+    /// Effect bringing the panel back when the screensaver ends.
+    pub fn show_panel(&self, theme: &Theme) -> Effect {
+        self.create("panel.show", Direction::default(), theme)
+    }
+
+    /// Effect playing on the link or tab title in focus for as long as it is.
+    pub fn focus(&self, theme: &Theme) -> Effect {
+        self.create("focus", Direction::default(), theme)
+    }
+
+    /// Effect bringing in freshly drawn content.
+    pub fn enter(&self, effect: TransitionEffect, direction: Direction, theme: &Theme) -> Effect {
+        self.create(&format!("{}.enter", effect.name()), direction, theme)
+    }
+
+    /// Effect taking away content that is no longer current.
+    pub fn leave(&self, effect: TransitionEffect, direction: Direction, theme: &Theme) -> Effect {
+        self.create(&format!("{}.leave", effect.name()), direction, theme)
+    }
+}
+
+fn build(spec: &EffectSpec, direction: Direction, theme: &Theme) -> Effect {
+    let all = |specs: &[EffectSpec]| -> Vec<Effect> {
+        specs
+            .iter()
+            .map(|spec| build(spec, direction, theme))
+            .collect()
+    };
+    let sweep = |sweep: &Sweep| {
+        (
+            motion(sweep.motion, direction),
+            sweep.gradient_length,
+            sweep.randomness,
+            color(sweep.color, theme),
+            timer(sweep.timer),
+        )
+    };
+    match spec {
+        EffectSpec::Sequence(specs) => fx::sequence(&all(specs)),
+        EffectSpec::Parallel(specs) => fx::parallel(&all(specs)),
+        EffectSpec::PingPong(spec) => fx::ping_pong(build(spec, direction, theme)),
+        EffectSpec::Repeating(spec) => fx::repeating(build(spec, direction, theme)),
+        EffectSpec::Delay(duration, spec) => {
+            fx::delay(timer(*duration), build(spec, direction, theme))
+        }
+        EffectSpec::Sleep(duration) => fx::sleep(timer(*duration)),
+        EffectSpec::Coalesce(duration) => fx::coalesce(timer(*duration)),
+        EffectSpec::CoalesceFrom(from, duration) => {
+            fx::coalesce_from(Style::default().bg(color(*from, theme)), timer(*duration))
+        }
+        EffectSpec::Dissolve(duration) => fx::dissolve(timer(*duration)),
+        EffectSpec::DissolveTo(to, duration) => {
+            fx::dissolve_to(Style::default().bg(color(*to, theme)), timer(*duration))
+        }
+        EffectSpec::SweepIn(spec) => {
+            let (motion, gradient_length, randomness, color, timer) = sweep(spec);
+            fx::sweep_in(motion, gradient_length, randomness, color, timer)
+        }
+        EffectSpec::SweepOut(spec) => {
+            let (motion, gradient_length, randomness, color, timer) = sweep(spec);
+            fx::sweep_out(motion, gradient_length, randomness, color, timer)
+        }
+        EffectSpec::SlideIn(spec) => {
+            let (motion, gradient_length, randomness, color, timer) = sweep(spec);
+            fx::slide_in(motion, gradient_length, randomness, color, timer)
+        }
+        EffectSpec::SlideOut(spec) => {
+            let (motion, gradient_length, randomness, color, timer) = sweep(spec);
+            fx::slide_out(motion, gradient_length, randomness, color, timer)
+        }
+        EffectSpec::FadeFrom(fg, bg, duration) => {
+            fx::fade_from(color(*fg, theme), color(*bg, theme), timer(*duration))
+        }
+        EffectSpec::FadeTo(fg, bg, duration) => {
+            fx::fade_to(color(*fg, theme), color(*bg, theme), timer(*duration))
+        }
+        EffectSpec::FadeFromFg(fg, duration) => {
+            fx::fade_from_fg(color(*fg, theme), timer(*duration))
+        }
+        EffectSpec::FadeToFg(fg, duration) => fx::fade_to_fg(color(*fg, theme), timer(*duration)),
+        EffectSpec::Glitch(percent, duration) => glitch(*percent, duration.ms),
+    }
+}
+
+/// `percent` of the characters flickering at random for `ms` milliseconds.
+fn glitch(percent: u16, ms: u32) -> Effect {
+    let glitch = fx::Glitch::builder()
+        .cell_glitch_ratio(percent as f32 / 100.0)
+        .action_start_delay_ms(0..ms / 2 + 1)
+        .action_ms(ms / 6..ms / 2 + 1)
+        .build()
+        .into_effect();
+    fx::with_duration(Duration::from_millis(ms), glitch)
+}
+
+fn timer(timer: Timer) -> EffectTimer {
+    EffectTimer::from_ms(timer.ms, interpolation(timer.easing))
+}
+
+fn motion(motion: MotionSpec, direction: Direction) -> Motion {
+    match motion {
+        MotionSpec::LeftToRight => Motion::LeftToRight,
+        MotionSpec::RightToLeft => Motion::RightToLeft,
+        MotionSpec::UpToDown => Motion::UpToDown,
+        MotionSpec::DownToUp => Motion::DownToUp,
+        MotionSpec::Forward => direction.motion(),
+        MotionSpec::Backward => direction.reversed().motion(),
+    }
+}
+
+fn color(color: ColorSpec, theme: &Theme) -> Color {
+    match color {
+        ColorSpec::Background => theme.background,
+        ColorSpec::Page => PAGE_COLOR,
+        ColorSpec::Rgb(r, g, b) => Color::Rgb(r, g, b),
+    }
+}
+
+fn interpolation(easing: Easing) -> Interpolation {
+    match easing {
+        Easing::Linear => Interpolation::Linear,
+        Easing::QuadIn => Interpolation::QuadIn,
+        Easing::QuadOut => Interpolation::QuadOut,
+        Easing::QuadInOut => Interpolation::QuadInOut,
+        Easing::CubicIn => Interpolation::CubicIn,
+        Easing::CubicOut => Interpolation::CubicOut,
+        Easing::CubicInOut => Interpolation::CubicInOut,
+        Easing::QuartIn => Interpolation::QuartIn,
+        Easing::QuartOut => Interpolation::QuartOut,
+        Easing::QuartInOut => Interpolation::QuartInOut,
+        Easing::QuintIn => Interpolation::QuintIn,
+        Easing::QuintOut => Interpolation::QuintOut,
+        Easing::QuintInOut => Interpolation::QuintInOut,
+        Easing::SineIn => Interpolation::SineIn,
+        Easing::SineOut => Interpolation::SineOut,
+        Easing::SineInOut => Interpolation::SineInOut,
+        Easing::CircIn => Interpolation::CircIn,
+        Easing::CircOut => Interpolation::CircOut,
+        Easing::CircInOut => Interpolation::CircInOut,
+        Easing::ExpoIn => Interpolation::ExpoIn,
+        Easing::ExpoOut => Interpolation::ExpoOut,
+        Easing::ExpoInOut => Interpolation::ExpoInOut,
+        Easing::ElasticIn => Interpolation::ElasticIn,
+        Easing::ElasticOut => Interpolation::ElasticOut,
+        Easing::ElasticInOut => Interpolation::ElasticInOut,
+        Easing::BounceIn => Interpolation::BounceIn,
+        Easing::BounceOut => Interpolation::BounceOut,
+        Easing::BounceInOut => Interpolation::BounceInOut,
+        Easing::BackIn => Interpolation::BackIn,
+        Easing::BackOut => Interpolation::BackOut,
+        Easing::BackInOut => Interpolation::BackInOut,
+    }
+}
+
+/// The content that was left animating out over the new content animating in.
+pub struct ContentTransition {
+    leaving: Option<(Buffer, Effect)>, // What the content area showed before
+    entering: Effect,
+}
+
+impl ContentTransition {
+    /// Only the new content animating in, e.g. when it changed in place.
+    pub fn enter(
+        effects: &Effects,
+        effect: TransitionEffect,
+        direction: Direction,
+        theme: &Theme,
+    ) -> Self {
+        Self {
+            leaving: None,
+            entering: effects.enter(effect, direction, theme),
+        }
+    }
+
+    /// `previous` content leaving with one effect while the new one enters with another.
+    pub fn replace(
+        effects: &Effects,
+        previous: Buffer,
+        leaving: TransitionEffect,
+        entering: TransitionEffect,
+        direction: Direction,
+        theme: &Theme,
+    ) -> Self {
+        Self {
+            leaving: Some((previous, effects.leave(leaving, direction, theme))),
+            entering: effects.enter(entering, direction, theme),
+        }
+    }
+
+    /// Plays the transition over `area`, where the new content is already drawn.
+    ///
+    /// Both effects play in the same frames: the leaving content is laid over the
+    /// entering one, which shows through wherever the leaving effect has cleared
+    /// a cell down to the `background` of the theme.
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, elapsed: Duration, background: Color) {
+        frame.render_effect(&mut self.entering, area, elapsed);
+        let Some((previous, effect)) = &mut self.leaving else {
+            return;
+        };
+        if !effect.running() {
+            self.leaving = None;
+            return;
+        }
+        let shared = area.intersection(previous.area);
+        let mut leaving = previous.clone();
+        effect.process(elapsed, &mut leaving, shared);
+        let buffer = frame.buffer_mut();
+        for y in shared.top()..shared.bottom() {
+            for x in shared.left()..shared.right() {
+                let cell = &leaving[(x, y)];
+                if !is_cleared(cell, background) {
+                    buffer[(x, y)] = cell.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Whether a leaving cell shows nothing but the background, letting the entering
+/// content through.
+fn is_cleared(cell: &Cell, background: Color) -> bool {
+    let plain = |color: Color| color == background || color == Color::Reset;
+    plain(cell.bg) && (cell.symbol().trim().is_empty() || cell.fg == background)
+}
+
+/// A copy of what `area` of `buffer` shows.
+pub fn snapshot(buffer: &Buffer, area: Rect) -> Buffer {
+    let area = area.intersection(buffer.area);
+    let mut copy = Buffer::empty(area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            copy[(x, y)] = buffer[(x, y)].clone();
+        }
+    }
+    copy
+}
+
+#[cfg(test)]
+mod tests {
+    use ratzilla::ratatui::backend::TestBackend;
+    use ratzilla::ratatui::Terminal;
+
+    use super::*;
+    use crate::ui::{BG_COLOR, THEMES};
+
+    /// Symbols shown after drawing `symbol` everywhere and playing `elapsed` of
+    /// the transition.
+    fn frame(transition: &mut ContentTransition, symbol: &str, elapsed: u32) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(10, 4)).unwrap();
+        let frame = terminal
+            .draw(|frame| {
+                let area = frame.area();
+                for cell in frame.buffer_mut().content.iter_mut() {
+                    cell.set_symbol(symbol);
+                }
+                let elapsed = Duration::from_millis(elapsed);
+                transition.render(frame, area, elapsed, BG_COLOR);
+            })
+            .unwrap();
+        frame.buffer.content.iter().map(Cell::symbol).collect()
+    }
+
+    #[test]
+    fn leaving_and_entering_content_play_together() {
+        let effects = Effects::load();
+        let mut previous = Buffer::empty(Rect::new(0, 0, 10, 4));
+        for cell in previous.content.iter_mut() {
+            cell.set_symbol("a");
+        }
+        let mut transition = ContentTransition::replace(
+            &effects,
+            previous,
+            TransitionEffect::Coalesce,
+            TransitionEffect::Coalesce,
+            Direction::Next,
+            &THEMES[0],
+        );
+
+        // The old content covers the new one at first
+        assert!(frame(&mut transition, "b", 0).chars().all(|c| c == 'a'));
+        // Halfway through, both show
+        let halfway = frame(&mut transition, "b", 250);
+        assert!(
+            halfway.contains('a') && halfway.contains('b'),
+            "{}",
+            halfway
+        );
+        // Once the old content has left, only the new one is drawn
+        let done = frame(&mut transition, "b", 300);
+        assert!(!done.contains('a'), "{}", done);
+    }
+
+    #[test]
+    fn cleared_cells_show_only_the_background() {
+        let mut cell = Cell::default();
+        assert!(is_cleared(&cell, BG_COLOR));
+        cell.set_symbol("x");
+        assert!(!is_cleared(&cell, BG_COLOR));
+        cell.set_fg(BG_COLOR);
+        assert!(is_cleared(&cell, BG_COLOR));
+        cell.set_bg(Color::Red);
+        assert!(!is_cleared(&cell, BG_COLOR));
+    }
+}
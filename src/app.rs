@@ -1,49 +1,423 @@
-use crate::TAB_TITLES;
+use crate::animations::Direction;
+use crate::boot::Boot;
+use crate::data::LINKS;
+use crate::i18n::Locale;
+use crate::keymap::Keymap;
+use crate::palette::Palette;
+use crate::screensaver::Screensaver;
+use crate::search::{Search, SearchHit};
+use crate::tabs::{registry, ListSelection, Tab, TabContext};
+use crate::ui::{Theme, THEMES};
+use ratzilla::event::KeyEvent;
+use ratzilla::ratatui::layout::Position;
 
 pub struct AppState {
+    pub(crate) boot: Option<Boot>, // Played before the site on a first visit
+    pub(crate) tabs: Vec<Box<dyn Tab>>,
     pub(crate) tab_index: usize,
     pub(crate) should_animate: bool,
-    pub(crate) scroll_positions: [u16; TAB_TITLES.len()], // One position for each tab
-    pub(crate) max_scroll: [u16; TAB_TITLES.len()],       // Track content heights for each tab
+    pub(crate) left_tab: Option<(usize, Direction)>, // Tab the content transition starts from
+    pub(crate) scroll_positions: Vec<Vec<u16>>,      // One position for each pane of each tab
+    pub(crate) max_scroll: Vec<Vec<u16>>,            // Track content heights for each pane
+    pub(crate) focused_pane: Vec<usize>,
+    pub(crate) selections: Vec<ListSelection>, // Cursor of the tabs that list entries
+    pub(crate) search: Search,
+    pub(crate) palette: Palette,
+    pub(crate) screensaver: Screensaver,
+    pub(crate) theme_index: usize,
+    pub(crate) locale: Locale,
+    pub(crate) animations_enabled: bool,
+    pub(crate) keymaps: Vec<Keymap>,
+    pub(crate) keymap_index: usize,
+    pub(crate) help_open: bool,
+    pub(crate) pointer: Option<Position>, // Cell under the mouse
+    pub(crate) focused_link: Option<usize>,
+    pub(crate) page_height: u16, // Visible rows (or columns) of the focused pane
 }
 
 impl AppState {
     pub(crate) fn new() -> Self {
+        let locale = Locale::detect();
+        Self::with_locale(locale, Boot::start(locale))
+    }
+
+    fn with_locale(locale: Locale, boot: Option<Boot>) -> Self {
+        let tabs = registry();
+        let tab_count = tabs.len();
+        let panes: Vec<Vec<u16>> = tabs.iter().map(|tab| vec![0; tab.pane_count()]).collect();
         Self {
+            boot,
+            tabs,
             tab_index: 0,
             should_animate: true,
-            scroll_positions: [0, 0, 0, 0],
-            max_scroll: [0, 0, 0, 0],
+            left_tab: None,
+            scroll_positions: panes.clone(),
+            max_scroll: panes,
+            focused_pane: vec![0; tab_count],
+            selections: vec![ListSelection::default(); tab_count],
+            search: Search::default(),
+            palette: Palette::default(),
+            screensaver: Screensaver::default(),
+            theme_index: 0,
+            locale,
+            animations_enabled: true,
+            keymaps: Keymap::presets(tab_count),
+            keymap_index: 0,
+            help_open: false,
+            pointer: None,
+            focused_link: None,
+            page_height: 0,
         }
     }
 
+    pub(crate) fn tab_titles(&self) -> Vec<&'static str> {
+        self.tabs
+            .iter()
+            .map(|tab| self.locale.message(tab.title()))
+            .collect()
+    }
+
+    pub(crate) fn keymap(&self) -> &Keymap {
+        &self.keymaps[self.keymap_index]
+    }
+
+    /// Whether a key press does something on the site, rather than only in the browser.
+    pub(crate) fn handles_key(&self, event: &KeyEvent) -> bool {
+        if self.search.editing || self.palette.open {
+            // Text goes to the query, chords stay the browser's
+            return !event.ctrl && !event.alt;
+        }
+        self.keymap().action_for(event).is_some()
+    }
+
+    pub(crate) fn cycle_keymap(&mut self) {
+        self.keymap_index = (self.keymap_index + 1) % self.keymaps.len();
+    }
+
+    pub(crate) fn theme(&self) -> &'static Theme {
+        &THEMES[self.theme_index]
+    }
+
+    pub(crate) fn toggle_theme(&mut self) {
+        self.theme_index = (self.theme_index + 1) % THEMES.len();
+    }
+
+    pub(crate) fn cycle_locale(&mut self) {
+        self.locale = self.locale.next();
+        // Hits point at lines of the texts in the previous language
+        self.search.close();
+        self.should_animate = true;
+    }
+
     pub(crate) fn next_tab(&mut self) {
-        self.tab_index = (self.tab_index + 1) % TAB_TITLES.len();
-        self.should_animate = true; // Trigger animation on tab change
+        self.change_tab((self.tab_index + 1) % self.tabs.len(), Direction::Next);
     }
 
     pub(crate) fn previous_tab(&mut self) {
-        if self.tab_index > 0 {
-            self.tab_index -= 1;
-        } else {
-            self.tab_index = TAB_TITLES.len() - 1;
+        let tab = (self.tab_index + self.tabs.len() - 1) % self.tabs.len();
+        self.change_tab(tab, Direction::Previous);
+    }
+
+    pub(crate) fn select_tab(&mut self, tab: usize) {
+        if self.tab_index != tab {
+            let direction = if tab > self.tab_index {
+                Direction::Next
+            } else {
+                Direction::Previous
+            };
+            self.change_tab(tab, direction);
         }
+    }
+
+    fn change_tab(&mut self, tab: usize, direction: Direction) {
+        self.left_tab = Some((self.tab_index, direction));
+        self.tab_index = tab;
         self.should_animate = true; // Trigger animation on tab change
     }
 
-    pub(crate) fn scroll_down(&mut self) {
-        if self.scroll_positions[self.tab_index] < self.max_scroll[self.tab_index] {
-            self.scroll_positions[self.tab_index] += 1;
+    /// Shows what a `#<tab>/<entry>` link points at, like those in the feeds.
+    ///
+    /// Tabs go by their English title in lower case. An unknown tab is ignored,
+    /// an unknown entry only shows its tab.
+    pub(crate) fn follow_link(&mut self, fragment: &str) {
+        let (tab_name, entry) = match fragment.split_once('/') {
+            Some((tab_name, entry)) => (tab_name, Some(entry)),
+            None => (fragment, None),
+        };
+        let Some(tab) = self
+            .tabs
+            .iter()
+            .position(|tab| Locale::English.message(tab.title()).to_lowercase() == tab_name)
+        else {
+            return;
+        };
+        self.tab_index = tab;
+        if let Some(entry) = entry {
+            let mut cx = TabContext {
+                focused_pane: 0,
+                scroll: &mut self.scroll_positions[tab],
+                selection: &mut self.selections[tab],
+            };
+            self.focused_pane[tab] = 0;
+            self.tabs[tab].open(entry, &mut cx);
         }
     }
 
+    /// Moves the keyboard focus to the next link, then back off the links.
+    pub(crate) fn focus_next_link(&mut self) {
+        self.focused_link = match self.focused_link {
+            None => Some(0),
+            Some(link) if link + 1 < LINKS.len() => Some(link + 1),
+            Some(_) => None,
+        };
+    }
+
+    /// Current tab and its focused pane.
+    pub(crate) fn focused(&self) -> (usize, usize) {
+        (self.tab_index, self.focused_pane[self.tab_index])
+    }
+
+    pub(crate) fn focus_next_pane(&mut self) {
+        let panes = self.tabs[self.tab_index].pane_count();
+        let focused = &mut self.focused_pane[self.tab_index];
+        *focused = (*focused + 1) % panes;
+    }
+
+    pub(crate) fn focus_previous_pane(&mut self) {
+        let panes = self.tabs[self.tab_index].pane_count();
+        let focused = &mut self.focused_pane[self.tab_index];
+        *focused = (*focused + panes - 1) % panes;
+    }
+
+    pub(crate) fn focus_pane(&mut self, tab: usize, pane: usize) {
+        self.select_tab(tab);
+        self.focused_pane[tab] = pane;
+    }
+
+    /// Focuses the pane of a search hit and lets its tab show the line.
+    pub(crate) fn reveal(&mut self, hit: SearchHit) {
+        self.focus_pane(hit.tab, hit.pane);
+        let mut cx = TabContext {
+            focused_pane: hit.pane,
+            scroll: &mut self.scroll_positions[hit.tab],
+            selection: &mut self.selections[hit.tab],
+        };
+        self.tabs[hit.tab].reveal(hit.pane, hit.line, &mut cx);
+    }
+
+    pub(crate) fn scroll_down(&mut self) {
+        self.scroll_by(1);
+    }
+
     pub(crate) fn scroll_up(&mut self) {
-        if self.scroll_positions[self.tab_index] > 0 {
-            self.scroll_positions[self.tab_index] -= 1;
-        }
+        self.scroll_by(-1);
     }
 
-    pub(crate) fn update_max_scroll(&mut self, tab: usize, new_max: u16) {
+    /// Scrolls the focused pane of the current tab, staying inside its range.
+    pub(crate) fn scroll_by(&mut self, delta: i32) {
+        let (tab, pane) = self.focused();
+        let position = self.scroll_positions[tab][pane] as i32 + delta;
+        self.scroll_positions[tab][pane] =
+            position.clamp(0, self.max_scroll[tab][pane] as i32) as u16;
+    }
+
+    pub(crate) fn page_down(&mut self) {
+        self.scroll_by(self.page_height.max(1) as i32);
+    }
+
+    pub(crate) fn page_up(&mut self) {
+        self.scroll_by(-(self.page_height.max(1) as i32));
+    }
+
+    pub(crate) fn half_page_down(&mut self) {
+        self.scroll_by((self.page_height / 2).max(1) as i32);
+    }
+
+    pub(crate) fn half_page_up(&mut self) {
+        self.scroll_by(-((self.page_height / 2).max(1) as i32));
+    }
+
+    pub(crate) fn scroll_to_top(&mut self) {
+        let (tab, pane) = self.focused();
+        self.scroll_positions[tab][pane] = 0;
+    }
+
+    pub(crate) fn scroll_to_bottom(&mut self) {
+        let (tab, pane) = self.focused();
+        self.scroll_positions[tab][pane] = self.max_scroll[tab][pane];
+    }
+
+    pub(crate) fn update_max_scroll(&mut self, tab: usize, mut new_max: Vec<u16>) {
+        // Panes the tab did not measure do not scroll
+        new_max.resize(self.scroll_positions[tab].len(), 0);
+        // Keep the content in view when the layout shrinks the scroll range
+        for (position, max) in self.scroll_positions[tab].iter_mut().zip(&new_max) {
+            *position = (*position).min(*max);
+        }
         self.max_scroll[tab] = new_max;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratzilla::event::KeyCode;
+
+    use super::*;
+    use crate::actions::Action;
+    use crate::i18n::Message;
+
+    /// State whose first pane scrolls `max_scroll` rows, `page_height` at a time.
+    fn state(max_scroll: u16, page_height: u16) -> AppState {
+        let mut state = AppState::with_locale(Locale::English, None);
+        state.update_max_scroll(0, vec![max_scroll]);
+        state.page_height = page_height;
+        state
+    }
+
+    fn position(state: &AppState) -> u16 {
+        state.scroll_positions[0][0]
+    }
+
+    #[test]
+    fn scroll_by_clamps_to_the_range() {
+        let mut state = state(10, 4);
+        state.scroll_by(-3);
+        assert_eq!(position(&state), 0);
+        state.scroll_by(7);
+        assert_eq!(position(&state), 7);
+        state.scroll_by(7);
+        assert_eq!(position(&state), 10);
+        state.scroll_by(-4);
+        assert_eq!(position(&state), 6);
+    }
+
+    #[test]
+    fn pages_move_by_the_page_height() {
+        let mut state = state(10, 4);
+        state.page_down();
+        assert_eq!(position(&state), 4);
+        state.page_down();
+        state.page_down();
+        assert_eq!(position(&state), 10);
+        state.page_up();
+        assert_eq!(position(&state), 6);
+        state.page_up();
+        state.page_up();
+        assert_eq!(position(&state), 0);
+    }
+
+    #[test]
+    fn half_pages_move_by_half_the_page_height() {
+        let mut state = state(10, 4);
+        state.half_page_down();
+        assert_eq!(position(&state), 2);
+        state.half_page_up();
+        state.half_page_up();
+        assert_eq!(position(&state), 0);
+    }
+
+    #[test]
+    fn tiny_pages_still_move() {
+        let mut state = state(10, 1);
+        state.half_page_down();
+        assert_eq!(position(&state), 1);
+        state.page_height = 0;
+        state.page_down();
+        assert_eq!(position(&state), 2);
+    }
+
+    #[test]
+    fn a_page_larger_than_the_content_reaches_the_end() {
+        let mut state = state(3, 20);
+        state.page_down();
+        assert_eq!(position(&state), 3);
+        state.half_page_up();
+        assert_eq!(position(&state), 0);
+    }
+
+    #[test]
+    fn top_and_bottom_jump_to_the_ends() {
+        let mut state = state(10, 4);
+        state.scroll_to_bottom();
+        assert_eq!(position(&state), 10);
+        state.scroll_to_top();
+        assert_eq!(position(&state), 0);
+    }
+
+    #[test]
+    fn content_that_fits_never_scrolls() {
+        let mut state = state(0, 4);
+        state.scroll_down();
+        state.page_down();
+        state.half_page_down();
+        state.scroll_to_bottom();
+        assert_eq!(position(&state), 0);
+        state.scroll_up();
+        state.page_up();
+        assert_eq!(position(&state), 0);
+    }
+
+    #[test]
+    fn vim_half_page_chords_are_kept_from_the_browser() {
+        let state = state(10, 4);
+        let ctrl = |c| KeyEvent {
+            code: KeyCode::Char(c),
+            ctrl: true,
+            alt: false,
+            shift: false,
+        };
+        assert!(state.handles_key(&ctrl('d')));
+        assert!(state.handles_key(&ctrl('u')));
+        assert!(!state.handles_key(&ctrl('r')));
+    }
+
+    #[test]
+    fn feed_links_open_their_entry() {
+        let mut state = state(0, 4);
+        let project = crate::data::PROJECTS[0].name.to_lowercase();
+        state.follow_link(&format!("projects/{}", project));
+        assert_eq!(state.tab_index, 1);
+        assert_eq!(state.tabs[1].pane_count(), 1); // The detail view
+
+        // Only drafts may be committed so far
+        let posts = crate::posts::POSTS;
+        if let Some(post) = posts.first() {
+            state.follow_link(&format!("posts/{}", post.slug));
+            assert_eq!(state.tab_index, 3);
+            let texts = state.tabs[3].searchable_texts(Locale::English);
+            assert!(texts[0].lines.len() > posts.len()); // The post rather than the list
+        }
+    }
+
+    #[test]
+    fn contact_downloads_only_from_its_tab() {
+        let mut state = state(0, 4);
+        assert!(!Action::DownloadContact.is_available(&state));
+        assert!(!Action::available(&state).contains(&Action::DownloadContact));
+        state.tab_index = state
+            .tabs
+            .iter()
+            .position(|tab| tab.title() == Message::Contact)
+            .unwrap();
+        assert!(Action::DownloadContact.is_available(&state));
+        assert!(Action::available(&state).contains(&Action::DownloadContact));
+    }
+
+    #[test]
+    fn unknown_links_open_nothing() {
+        let mut state = state(0, 4);
+        state.follow_link("nowhere/at-all");
+        assert_eq!(state.tab_index, 0);
+        state.follow_link("projects/nothing-by-that-name");
+        assert_eq!(state.tab_index, 1);
+        assert_eq!(state.tabs[1].pane_count(), 2); // Still the list
+    }
+
+    #[test]
+    fn a_shrinking_range_pulls_the_position_back() {
+        let mut state = state(10, 4);
+        state.scroll_to_bottom();
+        state.update_max_scroll(0, vec![5]);
+        assert_eq!(position(&state), 5);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::ui::{block_height, block_width};
+
+/// ASCII art drawn at several sizes, widest variant first.
+pub struct AsciiArt {
+    pub variants: &'static [&'static str],
+}
+
+impl AsciiArt {
+    /// Largest variant that fits in `width` × `height`, the smallest one when none does.
+    pub fn fit(&self, width: u16, height: u16) -> &'static str {
+        self.variants
+            .iter()
+            .copied()
+            .find(|art| block_width(art) <= width && block_height(art) <= height)
+            .unwrap_or_else(|| self.smallest())
+    }
+
+    /// Largest variant no wider than `width`, the smallest one when none is.
+    pub fn fit_width(&self, width: u16) -> &'static str {
+        self.fit(width, u16::MAX)
+    }
+
+    fn smallest(&self) -> &'static str {
+        self.variants.last().copied().unwrap_or_default()
+    }
+}
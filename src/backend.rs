@@ -0,0 +1,436 @@
+use std::io;
+
+use ratzilla::ratatui::backend::{Backend, WindowSize};
+use ratzilla::ratatui::buffer::{Buffer, Cell};
+use ratzilla::ratatui::layout::{Position, Rect, Size};
+use ratzilla::ratatui::style::{Color, Modifier};
+use ratzilla::ratatui::text::Span;
+use ratzilla::ratatui::widgets::Widget;
+use ratzilla::utils::{get_screen_size, get_window_size, is_mobile};
+use web_sys::wasm_bindgen::JsValue;
+use web_sys::{Document, Element};
+
+use crate::ui::display_width;
+
+/// Marks the cells of a [`Hyperlink`], which the backend wraps in an `<a>`.
+const HYPERLINK: Modifier = Modifier::SLOW_BLINK;
+
+/// A URL the visitor can click, drawn as its own text.
+pub struct Hyperlink<'a> {
+    url: Span<'a>,
+}
+
+impl<'a> Hyperlink<'a> {
+    pub fn new(url: impl Into<Span<'a>>) -> Self {
+        let url = url.into();
+        let style = url.style.add_modifier(HYPERLINK);
+        Self {
+            url: url.style(style),
+        }
+    }
+}
+
+impl Widget for Hyperlink<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.url.render(area, buf);
+    }
+}
+
+/// Draws the terminal into the page as a `<pre>` of `<span>`s for each row.
+///
+/// Takes the place of Ratzilla's `DomBackend`, which only wraps hyperlinks in
+/// anchors when it builds its grid and never updates their cells after that.
+/// Here a row is built again as soon as its links change, and the cells of a
+/// link are updated like any other.
+pub struct DomBackend {
+    document: Document,
+    grid: Element,
+    rows: Vec<Row>,
+}
+
+/// A row of the grid and the cells last drawn into it.
+struct Row {
+    pre: Element,
+    cells: Vec<Cell>,
+    spans: Vec<Element>, // One for each cell, inside the `<a>` of a link
+    links: Vec<Link>,    // Links the spans are wrapped for
+    changed: Vec<u16>,   // Columns drawn since the last flush
+}
+
+/// A run of hyperlink cells, pointing at the URL they spell out.
+#[derive(Debug, PartialEq, Eq)]
+struct Link {
+    start: usize,
+    end: usize,
+    url: String,
+}
+
+impl DomBackend {
+    /// Adds an empty grid to the page, as large as the window.
+    pub fn new() -> io::Result<Self> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or_else(|| io::Error::other("no document to draw into"))?;
+        let body = document
+            .body()
+            .ok_or_else(|| io::Error::other("the document has no body"))?;
+        let grid = document.create_element("div").map_err(js_error)?;
+        grid.set_id("grid");
+        body.append_child(&grid).map_err(js_error)?;
+        let mut backend = Self {
+            document,
+            grid,
+            rows: Vec::new(),
+        };
+        backend.clear()?;
+        Ok(backend)
+    }
+
+    /// Cells that fit the window, or the whole screen on a phone.
+    ///
+    /// A column and a row are left out, like Ratzilla does, as the cell size is
+    /// only an estimate of the font's.
+    fn fitting_size() -> Size {
+        let size = if is_mobile() {
+            get_screen_size()
+        } else {
+            get_window_size()
+        };
+        Size::new(size.width.saturating_sub(1), size.height.saturating_sub(1))
+    }
+}
+
+impl Backend for DomBackend {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        for (x, y, cell) in content {
+            let Some(row) = self.rows.get_mut(y as usize) else {
+                continue;
+            };
+            let Some(drawn) = row.cells.get_mut(x as usize) else {
+                continue;
+            };
+            if drawn != cell {
+                *drawn = cell.clone();
+                // The next cell shows or hides depending on whether this one is wide
+                row.changed.extend([x, x + 1]);
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        for row in &mut self.rows {
+            let changed = std::mem::take(&mut row.changed);
+            if changed.is_empty() {
+                continue;
+            }
+            let links = links(&row.cells);
+            if links != row.links {
+                row.links = links;
+                row.build(&self.document).map_err(js_error)?;
+            } else {
+                for x in changed {
+                    if (x as usize) < row.cells.len() {
+                        row.update_span(x as usize).map_err(js_error)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the grid again at the size of the window, also after a resize.
+    fn clear(&mut self) -> io::Result<()> {
+        let size = Self::fitting_size();
+        self.grid.set_inner_html("");
+        self.rows = (0..size.height)
+            .map(|_| Row::new(&self.document, &self.grid, size.width))
+            .collect::<Result<_, _>>()
+            .map_err(js_error)?;
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        Ok(Self::fitting_size())
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        Ok(WindowSize {
+            columns_rows: Self::fitting_size(),
+            pixels: Size::default(),
+        })
+    }
+
+    // The site never shows a cursor
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        Ok(Position::ORIGIN)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, _position: P) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Row {
+    fn new(document: &Document, grid: &Element, width: u16) -> Result<Row, JsValue> {
+        let pre = document.create_element("pre")?;
+        grid.append_child(&pre)?;
+        let mut row = Row {
+            pre,
+            cells: vec![Cell::default(); width as usize],
+            spans: Vec::new(),
+            links: Vec::new(),
+            changed: Vec::new(),
+        };
+        row.build(document)?;
+        Ok(row)
+    }
+
+    /// Creates the spans of the row from scratch, wrapping every link in an `<a>`.
+    fn build(&mut self, document: &Document) -> Result<(), JsValue> {
+        self.pre.set_inner_html("");
+        self.spans.clear();
+        let mut anchor: Option<Element> = None;
+        for x in 0..self.cells.len() {
+            if let Some(link) = self.links.iter().find(|link| link.start == x) {
+                let link_anchor = document.create_element("a")?;
+                link_anchor.set_attribute("href", &link.url)?;
+                link_anchor.set_attribute("style", "text-decoration: none;")?;
+                self.pre.append_child(&link_anchor)?;
+                anchor = Some(link_anchor);
+            }
+            let span = document.create_element("span")?;
+            anchor.as_ref().unwrap_or(&self.pre).append_child(&span)?;
+            self.spans.push(span);
+            self.update_span(x)?;
+            if self.links.iter().any(|link| link.end == x + 1) {
+                anchor = None;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draws the cell at `x` into its span.
+    fn update_span(&self, x: usize) -> Result<(), JsValue> {
+        let (text, css) = span_content(&self.cells, x);
+        let span = &self.spans[x];
+        span.set_text_content(Some(text));
+        span.set_attribute("style", &css)
+    }
+}
+
+/// Whether the cell at `x` is the second column of a wide character.
+fn is_covered(cells: &[Cell], x: usize) -> bool {
+    x > 0 && display_width(cells[x - 1].symbol()) > 1
+}
+
+/// Text and inline style of the span of the cell at `x`.
+///
+/// A wide character takes exactly two columns, and the cell it covers stays
+/// empty, as Ratatui never sends that cell again.
+fn span_content(cells: &[Cell], x: usize) -> (&str, String) {
+    let cell = &cells[x];
+    let mut css = cell_css(cell);
+    if is_covered(cells, x) {
+        return ("", css);
+    }
+    if display_width(cell.symbol()) > 1 {
+        css.push_str(" display: inline-block; width: 2ch;");
+    }
+    (cell.symbol(), css)
+}
+
+/// Runs of hyperlink cells in a row.
+///
+/// Ratatui resets the cell a wide character covers, so that cell belongs to
+/// the link of the character and adds nothing to its URL.
+fn links(cells: &[Cell]) -> Vec<Link> {
+    let is_link = |x: usize| {
+        x < cells.len()
+            && (cells[x].modifier.contains(HYPERLINK)
+                || is_covered(cells, x) && cells[x - 1].modifier.contains(HYPERLINK))
+    };
+    let mut links = Vec::new();
+    let mut x = 0;
+    while x < cells.len() {
+        if !is_link(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while is_link(x) {
+            x += 1;
+        }
+        links.push(Link {
+            start,
+            end: x,
+            url: (start..x)
+                .filter(|x| !is_covered(cells, *x))
+                .map(|x| cells[x].symbol())
+                .collect(),
+        });
+    }
+    links
+}
+
+/// Inline style of the `<span>` of a cell.
+fn cell_css(cell: &Cell) -> String {
+    let (mut fg, mut bg) = (css_color(cell.fg), css_color(cell.bg));
+    let reversed = cell.modifier.contains(Modifier::REVERSED);
+    if reversed {
+        std::mem::swap(&mut fg, &mut bg);
+    }
+    let white = || "rgb(255, 255, 255)".to_string();
+    let mut css = format!(
+        "color: {}; background-color: {};",
+        fg.unwrap_or_else(white),
+        match bg {
+            Some(bg) => bg,
+            None if reversed => white(),
+            None => "transparent".to_string(),
+        }
+    );
+    if cell.modifier.contains(Modifier::BOLD) {
+        css.push_str(" font-weight: bold;");
+    }
+    if cell.modifier.contains(Modifier::DIM) {
+        css.push_str(" opacity: 0.5;");
+    }
+    if cell.modifier.contains(Modifier::ITALIC) {
+        css.push_str(" font-style: italic;");
+    }
+    if cell.modifier.contains(Modifier::HIDDEN) {
+        css.push_str(" visibility: hidden;");
+    }
+    // Links are underlined by their cells, so the line takes their color
+    let underlined = cell.modifier.intersects(Modifier::UNDERLINED | HYPERLINK);
+    let crossed_out = cell.modifier.contains(Modifier::CROSSED_OUT);
+    match (underlined, crossed_out) {
+        (true, true) => css.push_str(" text-decoration: underline line-through;"),
+        (true, false) => css.push_str(" text-decoration: underline;"),
+        (false, true) => css.push_str(" text-decoration: line-through;"),
+        (false, false) => {}
+    }
+    css
+}
+
+fn css_color(color: Color) -> Option<String> {
+    let (r, g, b) = match color {
+        Color::Black => (0, 0, 0),
+        Color::Red => (128, 0, 0),
+        Color::Green => (0, 128, 0),
+        Color::Yellow => (128, 128, 0),
+        Color::Blue => (0, 0, 128),
+        Color::Magenta => (128, 0, 128),
+        Color::Cyan => (0, 128, 128),
+        Color::Gray => (192, 192, 192),
+        Color::DarkGray => (128, 128, 128),
+        Color::LightRed => (255, 0, 0),
+        Color::LightGreen => (0, 255, 0),
+        Color::LightYellow => (255, 255, 0),
+        Color::LightBlue => (0, 0, 255),
+        Color::LightMagenta => (255, 0, 255),
+        Color::LightCyan => (0, 255, 255),
+        Color::White => (255, 255, 255),
+        Color::Rgb(r, g, b) => (r, g, b),
+        _ => return None,
+    };
+    Some(format!("rgb({}, {}, {})", r, g, b))
+}
+
+fn js_error(error: JsValue) -> io::Error {
+    io::Error::other(format!("{:?}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use ratzilla::ratatui::style::Style;
+
+    use super::*;
+
+    /// Cells of a row `width` columns wide with `widget` drawn into it.
+    fn row(width: u16, widget: impl Widget) -> Vec<Cell> {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, 1));
+        widget.render(buffer.area, &mut buffer);
+        buffer.content
+    }
+
+    #[test]
+    fn wide_cells_span_two_columns() {
+        let cells = row(4, Span::raw("日x"));
+        let (text, css) = span_content(&cells, 0);
+        assert_eq!(text, "日");
+        assert!(css.ends_with(" display: inline-block; width: 2ch;"));
+        // The covered cell stays empty, the one after it is drawn as usual
+        assert_eq!(span_content(&cells, 1).0, "");
+        assert_eq!(span_content(&cells, 2), ("x", cell_css(&cells[2])));
+    }
+
+    #[test]
+    fn links_run_over_wide_cells() {
+        let cells = row(8, Hyperlink::new("a日b"));
+        assert_eq!(
+            links(&cells),
+            [Link {
+                start: 0,
+                end: 4,
+                url: "a日b".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn links_are_split_by_plain_cells() {
+        let mut cells = row(5, Hyperlink::new("ab cd"));
+        cells[2].modifier.remove(HYPERLINK);
+        let urls: Vec<_> = links(&cells).into_iter().map(|link| link.url).collect();
+        assert_eq!(urls, ["ab", "cd"]);
+    }
+
+    #[test]
+    fn cell_css_maps_colors_and_modifiers() {
+        assert_eq!(
+            cell_css(&Cell::default()),
+            "color: rgb(255, 255, 255); background-color: transparent;"
+        );
+
+        let mut cell = Cell::default();
+        cell.set_style(
+            Style::new()
+                .fg(Color::Rgb(1, 2, 3))
+                .bg(Color::Blue)
+                .add_modifier(Modifier::BOLD | Modifier::ITALIC | Modifier::CROSSED_OUT),
+        );
+        assert_eq!(
+            cell_css(&cell),
+            "color: rgb(1, 2, 3); background-color: rgb(0, 0, 128); font-weight: bold; \
+             font-style: italic; text-decoration: line-through;"
+        );
+    }
+
+    #[test]
+    fn cell_css_reverses_and_underlines_links() {
+        let mut cell = Cell::default();
+        cell.set_style(
+            Style::new()
+                .fg(Color::Black)
+                .add_modifier(Modifier::REVERSED | HYPERLINK | Modifier::CROSSED_OUT),
+        );
+        assert_eq!(
+            cell_css(&cell),
+            "color: rgb(255, 255, 255); background-color: rgb(0, 0, 0); \
+             text-decoration: underline line-through;"
+        );
+    }
+}
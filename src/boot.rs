@@ -0,0 +1,92 @@
+use ratzilla::utils::is_mobile;
+use web_time::Instant;
+
+use crate::data::{BOOT_LOG, LOGIN, MOTD};
+use crate::i18n::Locale;
+use crate::web;
+
+/// `localStorage` key set once a visitor has seen the boot sequence.
+const SEEN_KEY: &str = "emiv.boot-seen";
+
+/// Time the screen stays after the last line, before the site shows.
+const FINAL_PAUSE_MS: u64 = 1500;
+
+/// A line of the boot sequence, typed one character at a time.
+struct BootLine {
+    prompt: String, // Shown at once, before the typing starts
+    typed: String,
+    char_ms: u64,  // Time each typed character takes
+    pause_ms: u64, // Time before the next line starts
+}
+
+impl BootLine {
+    fn printed(text: &str) -> Self {
+        Self {
+            prompt: String::new(),
+            typed: text.to_string(),
+            char_ms: 4,
+            pause_ms: if text.is_empty() { 200 } else { 80 },
+        }
+    }
+
+    fn duration_ms(&self) -> u64 {
+        self.typed.chars().count() as u64 * self.char_ms + self.pause_ms
+    }
+}
+
+/// The fake boot and login played to first-time visitors before the site.
+pub struct Boot {
+    started: Instant,
+    lines: Vec<BootLine>,
+}
+
+impl Boot {
+    /// Starts the sequence, unless this visitor saw it already, is on a phone
+    /// without a keyboard to skip it, or asked for reduced motion.
+    pub(crate) fn start(locale: Locale) -> Option<Boot> {
+        if is_mobile() || web::prefers_reduced_motion() || web::load(SEEN_KEY).is_some() {
+            return None;
+        }
+        web::save(SEEN_KEY, "1");
+
+        let (host, user) = LOGIN;
+        let mut lines: Vec<BootLine> = BOOT_LOG
+            .iter()
+            .map(|line| BootLine::printed(line))
+            .collect();
+        lines.push(BootLine {
+            prompt: format!("{} login: ", host),
+            typed: user.to_string(),
+            char_ms: 150,
+            pause_ms: 600,
+        });
+        lines.push(BootLine::printed(""));
+        lines.extend(MOTD.get(locale).lines().map(|line| BootLine {
+            char_ms: 15,
+            ..BootLine::printed(line)
+        }));
+        Some(Boot {
+            started: Instant::now(),
+            lines,
+        })
+    }
+
+    /// The lines as far as they are typed by now, `None` once the sequence is over.
+    pub(crate) fn screen(&self) -> Option<Vec<String>> {
+        let mut remaining = self.started.elapsed().as_millis() as u64;
+        let mut screen = Vec::new();
+        for line in &self.lines {
+            let typed = (remaining / line.char_ms.max(1)) as usize;
+            screen.push(format!(
+                "{}{}",
+                line.prompt,
+                line.typed.chars().take(typed).collect::<String>()
+            ));
+            match remaining.checked_sub(line.duration_ms()) {
+                Some(left) => remaining = left,
+                None => return Some(screen),
+            }
+        }
+        (remaining < FINAL_PAUSE_MS).then_some(screen)
+    }
+}
@@ -0,0 +1,146 @@
+use qrcode::{Color as QrColor, EcLevel, QrCode};
+use ratzilla::ratatui::style::{Color, Style};
+use ratzilla::ratatui::text::{Line, Span};
+
+pub struct Contact {
+    pub first_name: &'static str,
+    pub last_name: &'static str,
+    pub email: &'static str,
+    pub phone: Option<&'static str>,
+    pub socials: &'static [(&'static str, &'static str)], // (label, url)
+}
+
+impl Contact {
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+
+    /// Builds a vCard 3.0 document for this contact.
+    pub fn to_vcard(&self) -> String {
+        let mut lines = vec![
+            "BEGIN:VCARD".to_string(),
+            "VERSION:3.0".to_string(),
+            format!(
+                "N:{};{};;;",
+                escape_vcard(self.last_name),
+                escape_vcard(self.first_name)
+            ),
+            format!("FN:{}", escape_vcard(&self.full_name())),
+            format!("EMAIL;TYPE=INTERNET:{}", escape_vcard(self.email)),
+        ];
+        if let Some(phone) = self.phone {
+            lines.push(format!("TEL;TYPE=CELL:{}", escape_vcard(phone)));
+        }
+        for (_, url) in self.socials {
+            lines.push(format!("URL:{}", escape_vcard(url)));
+        }
+        lines.push("END:VCARD".to_string());
+
+        // vCard lines are CRLF terminated
+        lines.join("\r\n") + "\r\n"
+    }
+
+    /// Renders the vCard as a QR code made of half-block characters.
+    ///
+    /// Every line covers two rows of modules, so the code stays roughly square
+    /// in a terminal cell grid.
+    pub fn qr_lines(&self) -> Vec<Line<'static>> {
+        // Low error correction keeps the code small enough to fit in the pane
+        let code = QrCode::with_error_correction_level(self.to_vcard(), EcLevel::L)
+            .expect("contact does not fit in a QR code");
+        qr_to_lines(&code)
+    }
+}
+
+/// Quiet zone around the QR code, in modules, the minimum the QR standard asks for.
+const QR_QUIET_ZONE: usize = 4;
+
+fn qr_to_lines(code: &QrCode) -> Vec<Line<'static>> {
+    let width = code.width();
+    let colors = code.to_colors();
+    let size = width + QR_QUIET_ZONE * 2;
+
+    let is_dark = |x: usize, y: usize| {
+        if x < QR_QUIET_ZONE || y < QR_QUIET_ZONE {
+            return false;
+        }
+        let (x, y) = (x - QR_QUIET_ZONE, y - QR_QUIET_ZONE);
+        x < width && y < width && colors[y * width + x] == QrColor::Dark
+    };
+
+    // Foreground paints the upper half, background the lower half
+    let style = Style::default().fg(Color::White).bg(Color::Black);
+    (0..size)
+        .step_by(2)
+        .map(|y| {
+            let row: String = (0..size)
+                .map(|x| match (is_dark(x, y), is_dark(x, y + 1)) {
+                    (false, false) => '█',
+                    (false, true) => '▀',
+                    (true, false) => '▄',
+                    (true, true) => ' ',
+                })
+                .collect();
+            Line::from(Span::styled(row, style))
+        })
+        .collect()
+}
+
+fn escape_vcard(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' | ',' | ';' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTACT: Contact = Contact {
+        first_name: "Ada",
+        last_name: "Lovelace, Countess",
+        email: "ada@example.com",
+        phone: Some("+44 20 0000 0000"),
+        socials: &[("Website", "https://example.com/a;b")],
+    };
+
+    #[test]
+    fn escape_vcard_escapes_separators_and_newlines() {
+        assert_eq!(escape_vcard(r"a\b,c;d"), r"a\\b\,c\;d");
+        assert_eq!(escape_vcard("two\nlines"), "two\\nlines");
+        assert_eq!(escape_vcard("plain"), "plain");
+    }
+
+    #[test]
+    fn to_vcard_writes_every_field_with_crlf() {
+        assert_eq!(
+            CONTACT.to_vcard(),
+            "BEGIN:VCARD\r\n\
+             VERSION:3.0\r\n\
+             N:Lovelace\\, Countess;Ada;;;\r\n\
+             FN:Ada Lovelace\\, Countess\r\n\
+             EMAIL;TYPE=INTERNET:ada@example.com\r\n\
+             TEL;TYPE=CELL:+44 20 0000 0000\r\n\
+             URL:https://example.com/a\\;b\r\n\
+             END:VCARD\r\n"
+        );
+    }
+
+    #[test]
+    fn to_vcard_leaves_out_a_missing_phone() {
+        let contact = Contact {
+            phone: None,
+            ..CONTACT
+        };
+        assert!(!contact.to_vcard().contains("TEL"));
+    }
+}
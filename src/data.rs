@@ -1,42 +1,53 @@
-pub const BANNER: &str = r#"
-░        ░░  ░░░░  ░░        ░░  ░░░░  ░
-▒  ▒▒▒▒▒▒▒▒   ▒▒   ▒▒▒▒▒  ▒▒▒▒▒  ▒▒▒▒  ▒
-▓      ▓▓▓▓        ▓▓▓▓▓  ▓▓▓▓▓▓  ▓▓  ▓▓
-█  ████████  █  █  █████  ███████    ███
-█        ██  ████  ██        █████  ████
-"#;
+use crate::art::AsciiArt;
+use crate::contact::Contact;
+use crate::i18n::Localized;
 
-pub const DESCRIPTION: &str = r#"Hi y’all, I'm Emirhan — a Rustacean v_(°v°)_v and senior Computer Engineering student at Yeditepe University, currently interning at smartPulse.
+// Also read by the native feed generator, so it must not depend on the rest of the crate
+mod projects;
+pub use projects::*;
+
+/// The shaded "EMIV" of `fonts/banner.txt`, then the same drawn by the build script
+/// with every bundled FIGlet font.
+pub const BANNER: AsciiArt = AsciiArt {
+    variants: include!(concat!(env!("OUT_DIR"), "/banner.rs")),
+};
+
+pub const DESCRIPTION: Localized = Localized::en(
+    r#"Hi y’all, I'm Emirhan — a Rustacean v_(°v°)_v and senior Computer Engineering student at Yeditepe University, currently interning at smartPulse.
 
 I’m into systems-level programming, distributed systems, P2P, and cryptography. Also a fan of F1, chess, photography, and cycling.
-"#;
+"#,
+)
+.tr(
+    r#"Herkese merhaba, ben Emirhan — bir Rustacean v_(°v°)_v ve Yeditepe Üniversitesi'nde son sınıf Bilgisayar Mühendisliği öğrencisiyim, şu anda smartPulse'ta stajyerim.
+
+Sistem seviyesinde programlama, dağıtık sistemler, P2P ve kriptografiyle ilgileniyorum. Ayrıca F1, satranç, fotoğrafçılık ve bisiklet hayranıyım.
+"#,
+);
 
-pub const EDUCATION: &[(&str, &str, &str)] = &[
+/// University, field, grade and duration.
+// TODO(owner): add the dates of the studies, e.g. `Some("September 2021 – June 2025")`.
+// The original site only listed the school, field and grade, so the timeline
+// leaves the studies out until then.
+pub const EDUCATION: &[(&str, Localized, &str, Option<&str>)] = &[
     (
         "Yeditepe University",
-        "B.Sc. in Computer Engineering",
+        Localized::en("B.Sc. in Computer Engineering").tr("Bilgisayar Mühendisliği Lisansı"),
         "GPA: 3.87",
+        None,
     ),
-    ("Yeditepe University", "Minor in Economics", "GPA: 3.70"),
-    (
-        "Hogeschool Utrecht",
-        "Exchange Program in Social Robotics",
-        "Score: 10/10",
-    ),
-];
-
-pub const PROJECTS: &[(&str, &str)] = &[
     (
-        "btc-vanity",
-        "Bitcoin vanity address generator library + CLI. (+25,000 downloads!!)",
+        "Yeditepe University",
+        Localized::en("Minor in Economics").tr("Ekonomi Yan Dalı"),
+        "GPA: 3.70",
+        None,
     ),
     (
-        "deloxide",
-        "Scrubs your threads clean with real-time deadlock detection and built-in log insights.",
+        "Hogeschool Utrecht",
+        Localized::en("Exchange Program in Social Robotics").tr("Sosyal Robotik Değişim Programı"),
+        "Score: 10/10",
+        None,
     ),
-    ("rlox-ast", "Lox language interpreter."),
-    ("RustyChain", "Basic blockchain implementation."),
-    ("AmongOS", "Small sussy operating system."),
 ];
 
 pub const CONTRIBUTIONS: &[(&str, &str, &str)] = &[(
@@ -45,27 +56,46 @@ pub const CONTRIBUTIONS: &[(&str, &str, &str)] = &[(
     "13 Commits",
 )];
 
-pub const EXPERIENCES: &[(&str, &str, &str, &str, &str)] = &[
+/// Company, title, duration and two things done there.
+pub type Experience = (&'static str, Localized, &'static str, Localized, Localized);
+
+pub const EXPERIENCES: &[Experience] = &[
     (
         "smartPulse Technology",
-        "Connectivity Intern",
+        Localized::en("Connectivity Intern").tr("Bağlantı Teknolojileri Stajyeri"),
         "March 2025 – Still",
-        "Implemented a internal device setup tool from start to finish using Rust and Ratatui.",
-        "Learned industrial communication protocols and basics of the energy production industry.",
+        Localized::en(
+            "Implemented a internal device setup tool from start to finish using Rust and Ratatui.",
+        )
+        .tr("Rust ve Ratatui ile şirket içi bir cihaz kurulum aracını baştan sona geliştirdim."),
+        Localized::en(
+            "Learned industrial communication protocols and basics of the energy production industry.",
+        )
+        .tr("Endüstriyel haberleşme protokollerini ve enerji üretim sektörünün temellerini öğrendim."),
     ),
     (
         "HyperHawk Hyperloop Team",
-        "Co-Head of SWE Department",
+        Localized::en("Co-Head of SWE Department").tr("Yazılım Departmanı Eş Başkanı"),
         "October 2024 – Still",
-        "Directed the project's development strategy and structure, driving key decision-making processes.",
-        "Developed and deployed MicroPython code for Raspberry Pi.",
+        Localized::en(
+            "Directed the project's development strategy and structure, driving key decision-making processes.",
+        )
+        .tr("Projenin geliştirme stratejisini ve yapısını yönettim, kilit karar süreçlerine öncülük ettim."),
+        Localized::en("Developed and deployed MicroPython code for Raspberry Pi.")
+            .tr("Raspberry Pi için MicroPython kodu geliştirip devreye aldım."),
     ),
     (
         "HyperHawk Hyperloop Team",
-        "Software Engineer",
-        "November 2022  – October 2024",
-        "Achieved 3rd place with the team in the International Teknofest Hyperloop Competition, 2023.",
-        "Developed and optimized Arduino and Raspberry Pi code using C++, C, and Golang.",
+        Localized::en("Software Engineer").tr("Yazılım Mühendisi"),
+        "November 2022 – October 2024",
+        Localized::en(
+            "Achieved 3rd place with the team in the International Teknofest Hyperloop Competition, 2023.",
+        )
+        .tr("Takımla birlikte Uluslararası Teknofest Hyperloop Yarışması 2023'te üçüncülük kazandım."),
+        Localized::en(
+            "Developed and optimized Arduino and Raspberry Pi code using C++, C, and Golang.",
+        )
+        .tr("C++, C ve Golang ile Arduino ve Raspberry Pi kodu geliştirip optimize ettim."),
     ),
 ];
 
@@ -79,13 +109,31 @@ pub const LINKS: &[(&str, &str)] = &[
     ("Website", "https://dev.emiv.online"),
 ];
 
-pub const MOBILE_INFO: &str = r#"Not mobile-friendly
+pub const CONTACT: Contact = Contact {
+    first_name: "Emirhan",
+    last_name: "TALA",
+    email: "tala.emirhan@gmail.com",
+    phone: None,
+    socials: LINKS,
+};
+
+pub const MOBILE_INFO: Localized = Localized::en(
+    r#"Not mobile-friendly
 
 please use a desktop or
 visit the mobile-friendly site.
-"#;
+"#,
+)
+.tr(r#"Mobil uyumlu değil
+
+lütfen bir masaüstü kullanın ya da
+mobil uyumlu siteyi ziyaret edin.
+"#);
 
-pub const FERRIS_RATATUI_AND_UNSAFE_FERRIS: &str = r#"                    +++ ++++++                           .+%@@%.                   .    .. :  .. .
+/// Ferris, the Ratatui mouse and Unsafe Ferris, then fewer of them as room runs out.
+pub const FERRIS_ART: AsciiArt = AsciiArt {
+    variants: &[
+        r#"                    +++ ++++++                           .+%@@%.                   .    .. :  .. .
                + +++++++++++++++++                     .+@@@@@@.                 ..   =. .---.=. +..:.=
               +++++++++++++++++++++++        ++       -%@@@@@@@:              .   .=...=.:+-=--:+--=.=..--:.
  ++++++    ++++++++++++++++++++++++++++    ++++ ++   .*@@@@@@@@@=   ......     .=.: :=:==:===++*+++*++=.-=--.
@@ -99,4 +147,71 @@ pub const FERRIS_RATATUI_AND_UNSAFE_FERRIS: &str = r#"                    +++ ++
        ++++##     ################    ###+++   .=*      +=    .##%@#+*@:    ..:=++***********#***************+=-:.
          +++ #                        # ++*    %=               :#=..+%.       ..-**********************-+***+-.
            ++                          ++       .*-               -**#:             .. .-==-=:-......      ..
-"#;
+"#,
+        r#"                    +++ ++++++                           .+%@@%.
+               + +++++++++++++++++                     .+@@@@@@.
+              +++++++++++++++++++++++        ++       -%@@@@@@@:
+ ++++++    ++++++++++++++++++++++++++++    ++++ ++   .*@@@@@@@@@=   ......
+++++++++ +++++++++++++++++++++++++++++++++++++++++    ....#@@@@%*#%@@@@@@*
+++++++++ ++++++++++++++++++++++++++++++++*+++++++           =#-%= +@@@@@@-
+ +++++++++++++++++++++++++++++++++++++++++++++++        .#@@@@@@@@@@@@#:
+    ++++++++++++++++=.+@#++*=.%#++++++++++++++         .-%@@@@@@@@@@#:
+      ++++++++++++++#@@@@++#@@@@++++++++++++         .*=.%: =@@@@@@#:
+     ++++***+++++++++*%#*+++*##+++++++****++++     .++ .%: %+@@@@@@#:
+      +++*####*****++++++#@#*+++****#####*+++    .=+   :-   .%*@@@@@@@%.
+       ++++##     ################    ###+++   .=*      +=    .##%@#+*@:
+         +++ #                        # ++*    %=               :#=..+%.
+           ++                          ++       .*-               -**#:
+"#,
+        r#"                    +++ ++++++
+               + +++++++++++++++++
+              +++++++++++++++++++++++        ++
+ ++++++    ++++++++++++++++++++++++++++    ++++ ++
+++++++++ +++++++++++++++++++++++++++++++++++++++++
+++++++++ ++++++++++++++++++++++++++++++++*+++++++
+ +++++++++++++++++++++++++++++++++++++++++++++++
+    ++++++++++++++++=.+@#++*=.%#++++++++++++++
+      ++++++++++++++#@@@@++#@@@@++++++++++++
+     ++++***+++++++++*%#*+++*##+++++++****++++
+      +++*####*****++++++#@#*+++****#####*+++
+       ++++##     ################    ###+++
+         +++ #                        # ++*
+           ++                          ++
+"#,
+        r#"    _~^~^~_
+\) /  o o  \ (/
+  '_   -   _'
+  / '-----' \
+"#,
+    ],
+};
+
+/// What the fake machine prints while it boots, before anyone logs in.
+pub const BOOT_LOG: &[&str] = &[
+    "EMIV BIOS v4.2, An Energy Star Ally",
+    "Copyright (C) 1998-2026, Emiv Megatrends Inc.",
+    "",
+    "Main Processor : Ferris(R) Crab CPU @ 4.20GHz",
+    "Memory Test    : 65536K OK",
+    "",
+    "Detecting Primary Master ... WASM32 VIRTUAL DISK",
+    "Detecting Primary Slave  ... None",
+    "",
+    "Booting from WASM32 VIRTUAL DISK...",
+    "Loading ratzilla ............ done",
+    "Starting tachyonfx .......... done",
+    "",
+];
+
+/// Host name shown in the login prompt, and the user logging in.
+pub const LOGIN: (&str, &str) = ("emiv.tr", "guest");
+
+/// Message of the day, shown right after logging in.
+pub const MOTD: Localized = Localized::en(
+    r#"Welcome to emiv.tr, the home of Emirhan TALA.
+Have a look around, or press ? to see every key.
+"#,
+)
+.tr(r#"emiv.tr'ye, Emirhan TALA'nın evine hoş geldiniz.
+Etrafa göz atın ya da tüm tuşlar için ? tuşuna basın.
+"#);
@@ -0,0 +1,87 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)] // Until the projects are given their statuses
+pub enum ProjectStatus {
+    Active,
+    Maintained,
+    Finished,
+}
+
+impl ProjectStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProjectStatus::Active => "Active",
+            ProjectStatus::Maintained => "Maintained",
+            ProjectStatus::Finished => "Finished",
+        }
+    }
+}
+
+pub struct Project {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub repository: &'static str,
+    pub crate_name: Option<&'static str>, // Published on crates.io under this name
+    pub tags: &'static [&'static str],
+    pub status: Option<ProjectStatus>,
+    pub write_up: Option<&'static str>, // Longer than the description, for the detail view
+}
+
+impl Project {
+    pub fn crates_io_url(&self) -> Option<String> {
+        self.crate_name
+            .map(|name| format!("https://crates.io/crates/{}", name))
+    }
+}
+
+// TODO(owner): fill in the status and write-up of every project, and the crate
+// names of the other published ones. Only the names and descriptions come from
+// the original site; the repositories follow the GitHub profile in `LINKS` and
+// the tags only repeat what the descriptions say.
+pub const PROJECTS: &[Project] = &[
+    Project {
+        name: "btc-vanity",
+        description: "Bitcoin vanity address generator library + CLI.",
+        repository: "https://github.com/Emivvvvv/btc-vanity",
+        crate_name: Some("btc-vanity"),
+        tags: &["Bitcoin", "CLI"],
+        status: None,
+        write_up: None,
+    },
+    Project {
+        name: "deloxide",
+        description:
+            "Scrubs your threads clean with real-time deadlock detection and built-in log insights.",
+        repository: "https://github.com/Emivvvvv/deloxide",
+        crate_name: None,
+        tags: &["Concurrency"],
+        status: None,
+        write_up: None,
+    },
+    Project {
+        name: "rlox-ast",
+        description: "Lox language interpreter.",
+        repository: "https://github.com/Emivvvvv/rlox-ast",
+        crate_name: None,
+        tags: &["Interpreter"],
+        status: None,
+        write_up: None,
+    },
+    Project {
+        name: "RustyChain",
+        description: "Basic blockchain implementation.",
+        repository: "https://github.com/Emivvvvv/RustyChain",
+        crate_name: None,
+        tags: &["Blockchain"],
+        status: None,
+        write_up: None,
+    },
+    Project {
+        name: "AmongOS",
+        description: "Small sussy operating system.",
+        repository: "https://github.com/Emivvvvv/AmongOS",
+        crate_name: None,
+        tags: &["OS"],
+        status: None,
+        write_up: None,
+    },
+];
@@ -0,0 +1,217 @@
+use crate::i18n::{Locale, Message};
+
+mod calendar;
+pub use calendar::*;
+
+impl Date {
+    /// Today in UTC, from the browser clock.
+    pub fn today() -> Date {
+        let seconds = web_time::SystemTime::now()
+            .duration_since(web_time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Date::from_days_since_epoch((seconds / 86_400) as i64)
+    }
+}
+
+/// A month of a year, the precision durations are written with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Month {
+    pub year: i32,
+    pub month: u32, // 1 to 12
+}
+
+impl Month {
+    /// Parses `March 2025` or `Mar 2025`, any run of whitespace between the two.
+    pub fn parse(value: &str) -> Result<Month, String> {
+        let mut words = value.split_whitespace();
+        let (Some(name), Some(year), None) = (words.next(), words.next(), words.next()) else {
+            return Err(format!(
+                "expected a month and a year, got {:?}",
+                value.trim()
+            ));
+        };
+        let lowercase = name.to_lowercase();
+        let month = (1..=12)
+            .find(|month| {
+                let full = Locale::English.month_name(*month).to_lowercase();
+                lowercase.len() >= 3 && full.starts_with(&lowercase)
+            })
+            .ok_or_else(|| format!("unknown month {:?}", name))?;
+        let year = year
+            .parse()
+            .map_err(|_| format!("invalid year {:?}", year))?;
+        Ok(Month { year, month })
+    }
+
+    pub fn of(date: Date) -> Month {
+        Month {
+            year: date.year,
+            month: date.month,
+        }
+    }
+
+    /// The current month, from the browser clock.
+    pub fn current() -> Month {
+        Month::of(Date::today())
+    }
+
+    /// Months since January of year 0, to measure distances between months.
+    pub fn index(&self) -> i32 {
+        self.year * 12 + self.month as i32 - 1
+    }
+}
+
+/// A period between two months, open-ended while it is still going on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateRange {
+    pub start: Month,
+    pub end: Option<Month>, // `None` until present
+}
+
+impl DateRange {
+    /// Parses `November 2022 – October 2024`, `March 2025 – Still` or a single
+    /// month such as `June 2024`.
+    ///
+    /// The end may also be `Present` or `Now`, and must not come before the start.
+    pub fn parse(value: &str) -> Result<DateRange, String> {
+        let Some((start, end)) = value.split_once(['–', '-']) else {
+            let month = Month::parse(value)?;
+            return Ok(DateRange {
+                start: month,
+                end: Some(month),
+            });
+        };
+        let start = Month::parse(start)?;
+        let end = match end.trim().to_lowercase().as_str() {
+            "still" | "present" | "now" => None,
+            _ => Some(Month::parse(end)?),
+        };
+        if end.is_some_and(|end| end < start) {
+            return Err(format!("{:?} ends before it starts", value.trim()));
+        }
+        Ok(DateRange { start, end })
+    }
+
+    /// Last month of the range, the current one while it is ongoing.
+    pub fn end_or(&self, today: Month) -> Month {
+        self.end.unwrap_or(today)
+    }
+
+    /// Months covered, both ends included.
+    pub fn months(&self, today: Month) -> u32 {
+        (self.end_or(today).index() - self.start.index() + 1).max(0) as u32
+    }
+
+    /// Length of the range as `1 yr 8 mos`.
+    pub fn duration(&self, today: Month, locale: Locale) -> String {
+        let months = self.months(today);
+        let (years, months) = (months / 12, months % 12);
+        let unit = |count: u32, singular: Message, plural: Message| {
+            let unit = if count == 1 { singular } else { plural };
+            format!("{} {}", count, locale.message(unit))
+        };
+        let years_part = unit(years, Message::Year, Message::Years);
+        let months_part = unit(months, Message::Month, Message::Months);
+        match (years, months) {
+            (0, _) => months_part,
+            (_, 0) => years_part,
+            _ => format!("{} {}", years_part, months_part),
+        }
+    }
+
+    /// The range as `November 2022 – October 2024` or `March 2025 – Present`.
+    pub fn label(&self, locale: Locale) -> String {
+        let month = |month: Month| format!("{} {}", locale.month_name(month.month), month.year);
+        let end = self
+            .end
+            .map_or_else(|| locale.message(Message::Present).to_string(), month);
+        format!("{} – {}", month(self.start), end)
+    }
+
+    /// Newest first: ongoing ranges, then by end and start, latest first.
+    pub fn newest_first(&self, other: &DateRange) -> std::cmp::Ordering {
+        let key = |range: &DateRange| (range.end.is_none(), range.end, range.start);
+        key(other).cmp(&key(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{EDUCATION, EXPERIENCES};
+
+    fn month(year: i32, month: u32) -> Month {
+        Month { year, month }
+    }
+
+    #[test]
+    fn every_duration_in_the_data_parses() {
+        let experiences = EXPERIENCES.iter().map(|experience| experience.2);
+        let studies = EDUCATION.iter().filter_map(|study| study.3);
+        for duration in experiences.chain(studies) {
+            if let Err(error) = DateRange::parse(duration) {
+                panic!("{:?} does not parse: {}", duration, error);
+            }
+        }
+    }
+
+    #[test]
+    fn parses_closed_and_ongoing_ranges() {
+        assert_eq!(
+            DateRange::parse("November 2022 – October 2024"),
+            Ok(DateRange {
+                start: month(2022, 11),
+                end: Some(month(2024, 10)),
+            })
+        );
+        for end in ["Still", "present", "NOW"] {
+            assert_eq!(
+                DateRange::parse(&format!("March 2025 – {}", end)),
+                Ok(DateRange {
+                    start: month(2025, 3),
+                    end: None,
+                })
+            );
+        }
+        assert_eq!(
+            DateRange::parse("Sep  2021-Jun 2025"),
+            Ok(DateRange {
+                start: month(2021, 9),
+                end: Some(month(2025, 6)),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_single_months() {
+        let june = DateRange {
+            start: month(2024, 6),
+            end: Some(month(2024, 6)),
+        };
+        assert_eq!(DateRange::parse("June 2024"), Ok(june));
+        assert_eq!(DateRange::parse("June 2024 – June 2024"), Ok(june));
+        assert_eq!(june.months(month(2026, 1)), 1);
+        assert_eq!(june.duration(month(2026, 1), Locale::English), "1 mo");
+    }
+
+    #[test]
+    fn rejects_broken_ranges() {
+        assert!(DateRange::parse("October 2024 – November 2022").is_err());
+        assert!(DateRange::parse("Smarch 2024 – Still").is_err());
+        assert!(DateRange::parse("Ju 2024").is_err());
+        assert!(DateRange::parse("June – Still").is_err());
+        assert!(DateRange::parse("2024").is_err());
+        assert!(DateRange::parse("").is_err());
+    }
+
+    #[test]
+    fn ongoing_ranges_run_until_today() {
+        let range = DateRange::parse("March 2025 – Still").unwrap();
+        assert_eq!(range.months(month(2025, 3)), 1);
+        assert_eq!(range.months(month(2026, 5)), 15);
+        assert_eq!(
+            range.duration(month(2026, 5), Locale::English),
+            "1 yr 3 mos"
+        );
+    }
+}
@@ -0,0 +1,421 @@
+//! FIGlet fonts (`.flf`) and text drawn with them, following the FIGfont 2 spec:
+//! characters are laid out left to right at full width, fitted until they touch,
+//! or smushed into each other by the font's horizontal smushing rules.
+//!
+//! Shared by the build script, which draws the banner, and the site, which draws
+//! section headers.
+
+use std::collections::HashMap;
+
+/// Every bundled font as `(name, source)`, the most detailed first.
+pub const FONTS: &[(&str, &str)] = &[
+    ("small", include_str!("../fonts/small.flf")),
+    ("blocks", include_str!("../fonts/blocks.flf")),
+    ("term", include_str!("../fonts/term.flf")),
+];
+
+/// Characters every font defines, in the order they follow the header.
+const REQUIRED_CHARACTERS: [u32; 7] = [196, 214, 220, 228, 246, 252, 223]; // After ASCII 32 to 126
+
+// Horizontal smushing rules of the layout bits
+const EQUAL_CHARACTER: u32 = 1;
+const UNDERSCORE: u32 = 2;
+const HIERARCHY: u32 = 4;
+const OPPOSITE_PAIR: u32 = 8;
+const BIG_X: u32 = 16;
+const HARDBLANK: u32 = 32;
+const FITTING: u32 = 64;
+const SMUSHING: u32 = 128;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Layout {
+    FullWidth,
+    Fitting,
+    Smushing(u32), // Rule bits, none meaning universal smushing
+}
+
+pub struct Font {
+    hardblank: char,
+    height: usize,
+    layout: Layout,
+    glyphs: HashMap<char, Vec<Vec<char>>>, // Rows of equal width
+}
+
+impl Font {
+    pub fn parse(source: &str) -> Result<Font, String> {
+        let mut lines = source.lines();
+        let header = lines.next().ok_or("empty font")?;
+        let signature = header
+            .strip_prefix("flf2a")
+            .ok_or("not a FIGlet font, the header must start with `flf2a`")?;
+        let hardblank = signature.chars().next().ok_or("missing hardblank")?;
+        let fields = signature[hardblank.len_utf8()..]
+            .split_whitespace()
+            .map(|field| {
+                field
+                    .parse::<i64>()
+                    .map_err(|_| format!("invalid header field `{}`", field))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let &[height, _baseline, _max_length, old_layout, comment_lines, ..] = &fields[..] else {
+            return Err("the header needs at least five numbers".to_string());
+        };
+        if height < 1 {
+            return Err(format!("invalid height {}", height));
+        }
+        // The full layout, when given, replaces the old one
+        let layout = match fields.get(6) {
+            Some(&full_layout) if full_layout & SMUSHING as i64 != 0 => {
+                Layout::Smushing(full_layout as u32 & 63)
+            }
+            Some(&full_layout) if full_layout & FITTING as i64 != 0 => Layout::Fitting,
+            Some(_) => Layout::FullWidth,
+            None if old_layout < 0 => Layout::FullWidth,
+            None if old_layout == 0 => Layout::Fitting,
+            None => Layout::Smushing(old_layout as u32 & 63),
+        };
+        let mut lines = lines.skip(comment_lines.max(0) as usize).peekable();
+
+        let mut font = Font {
+            hardblank,
+            height: height as usize,
+            layout,
+            glyphs: HashMap::new(),
+        };
+        // Fonts may stop early, leaving the remaining characters undefined
+        for code in (32..127).chain(REQUIRED_CHARACTERS) {
+            if lines.peek().is_none() {
+                return Ok(font);
+            }
+            let glyph = font.read_glyph(&mut lines, code)?;
+            font.insert(code, glyph);
+        }
+        // Code-tagged characters, each after a line starting with its code
+        while let Some(tag) = lines.next() {
+            if tag.trim().is_empty() {
+                continue;
+            }
+            let code = parse_code(tag.split_whitespace().next().unwrap_or_default())
+                .ok_or_else(|| format!("invalid character code in `{}`", tag))?;
+            let glyph = font.read_glyph(&mut lines, code)?;
+            if let Ok(code) = u32::try_from(code) {
+                font.insert(code, glyph);
+            }
+        }
+        Ok(font)
+    }
+
+    fn read_glyph<'a>(
+        &self,
+        lines: &mut impl Iterator<Item = &'a str>,
+        code: impl std::fmt::Display,
+    ) -> Result<Vec<Vec<char>>, String> {
+        let mut rows = Vec::with_capacity(self.height);
+        for _ in 0..self.height {
+            let line = lines
+                .next()
+                .ok_or_else(|| format!("character {} has fewer than {} rows", code, self.height))?
+                .trim_end_matches(['\r', ' ']);
+            // The last character of a row is its end mark, doubled on the last row
+            let end_mark = line.chars().last().unwrap_or(' ');
+            rows.push(line.trim_end_matches(end_mark).chars().collect::<Vec<_>>());
+        }
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, ' ');
+        }
+        Ok(rows)
+    }
+
+    fn insert(&mut self, code: u32, glyph: Vec<Vec<char>>) {
+        if let Some(c) = char::from_u32(code) {
+            self.glyphs.insert(c, glyph);
+        }
+    }
+
+    /// `text` drawn with the font, `height` rows per line of text. Characters the
+    /// font lacks are left out.
+    pub fn render(&self, text: &str) -> Vec<String> {
+        let mut rows = Vec::new();
+        for line in text.lines() {
+            let mut output = vec![Vec::new(); self.height];
+            let mut previous_width = 0;
+            for c in line.chars() {
+                let Some(glyph) = self.glyphs.get(&c) else {
+                    continue;
+                };
+                self.append(&mut output, glyph, previous_width);
+                previous_width = glyph[0].len();
+            }
+            rows.extend(output.into_iter().map(|row| {
+                let row = row.into_iter().collect::<String>();
+                row.replace(self.hardblank, " ").trim_end().to_string()
+            }));
+        }
+        rows
+    }
+
+    fn append(&self, output: &mut [Vec<char>], glyph: &[Vec<char>], previous_width: usize) {
+        let width = glyph[0].len();
+        let overlap = self.overlap(output, glyph, previous_width);
+        for (row, glyph_row) in output.iter_mut().zip(glyph) {
+            // Like figlet, drop the blank columns that move past the start of the line
+            let skipped = overlap.saturating_sub(row.len());
+            let start = row.len() + skipped - overlap;
+            for (k, &right) in glyph_row[skipped..overlap].iter().enumerate() {
+                let left = row[start + k];
+                row[start + k] = self
+                    .smush(left, right, previous_width, width)
+                    .unwrap_or(right);
+            }
+            row.extend_from_slice(&glyph_row[overlap..]);
+        }
+    }
+
+    /// Columns the glyph can move into what is already drawn.
+    fn overlap(&self, output: &[Vec<char>], glyph: &[Vec<char>], previous_width: usize) -> usize {
+        let width = glyph[0].len();
+        if self.layout == Layout::FullWidth {
+            return 0;
+        }
+        let mut overlap = width;
+        for (row, glyph_row) in output.iter().zip(glyph) {
+            // Blank columns at the end of the row and at the start of the glyph
+            let trailing = row.iter().rev().take_while(|c| **c == ' ').count();
+            let leading = glyph_row.iter().take_while(|c| **c == ' ').count();
+            let mut amount = trailing + leading;
+            if trailing < row.len() && leading < glyph_row.len() {
+                let left = row[row.len() - trailing - 1];
+                let right = glyph_row[leading];
+                if self.smush(left, right, previous_width, width).is_some() {
+                    amount += 1;
+                }
+            }
+            overlap = overlap.min(amount);
+        }
+        overlap
+    }
+
+    /// The character drawn where `left` and `right` overlap, `None` when they can't.
+    fn smush(
+        &self,
+        left: char,
+        right: char,
+        left_width: usize,
+        right_width: usize,
+    ) -> Option<char> {
+        if left == ' ' {
+            return Some(right);
+        }
+        if right == ' ' {
+            return Some(left);
+        }
+        let Layout::Smushing(rules) = self.layout else {
+            return None;
+        };
+        if left_width < 2 || right_width < 2 {
+            return None;
+        }
+        let hardblank = self.hardblank;
+        if rules == 0 {
+            // Universal smushing, the later character wins over all but hardblanks
+            return Some(if right == hardblank { left } else { right });
+        }
+        if left == hardblank || right == hardblank {
+            return (rules & HARDBLANK != 0 && left == right).then_some(left);
+        }
+        if rules & EQUAL_CHARACTER != 0 && left == right {
+            return Some(left);
+        }
+        if rules & UNDERSCORE != 0 {
+            if left == '_' && "|/\\[]{}()<>".contains(right) {
+                return Some(right);
+            }
+            if right == '_' && "|/\\[]{}()<>".contains(left) {
+                return Some(left);
+            }
+        }
+        if rules & HIERARCHY != 0 {
+            let class = |c: char| {
+                ["|", "/\\", "[]", "{}", "()", "<>"]
+                    .iter()
+                    .position(|class| class.contains(c))
+            };
+            if let (Some(left_class), Some(right_class)) = (class(left), class(right)) {
+                if left_class != right_class {
+                    return Some(if left_class > right_class {
+                        left
+                    } else {
+                        right
+                    });
+                }
+            }
+        }
+        if rules & OPPOSITE_PAIR != 0
+            && matches!(
+                (left, right),
+                ('[', ']') | (']', '[') | ('{', '}') | ('}', '{') | ('(', ')') | (')', '(')
+            )
+        {
+            return Some('|');
+        }
+        if rules & BIG_X != 0 {
+            match (left, right) {
+                ('/', '\\') => return Some('|'),
+                ('\\', '/') => return Some('Y'),
+                ('>', '<') => return Some('X'),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// A character code in decimal, `0x` hexadecimal or `0` octal, maybe negative.
+fn parse_code(code: &str) -> Option<i64> {
+    let (negative, digits) = match code.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, code),
+    };
+    let value = if let Some(hex) = digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+        i64::from_str_radix(hex, 16).ok()?
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        i64::from_str_radix(&digits[1..], 8).ok()?
+    } else {
+        digits.parse().ok()?
+    };
+    Some(if negative { -value } else { value })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::*;
+
+    /// Texts the reference fixtures are drawn for, as `(name, text)`.
+    ///
+    /// Keep in sync with `tools/figlet-fixtures.sh`.
+    const SAMPLES: &[(&str, &str)] = &[
+        ("banner", "EMIV"),
+        ("pangram", "The quick brown fox jumps over the lazy dog"),
+        ("symbols", "/\\[]{}()<>|_"),
+    ];
+
+    /// A font one row high, with `glyphs` and a blank for every other character.
+    fn font(full_layout: u32, glyphs: &[(char, &str)]) -> Font {
+        let mut source = format!("flf2a$ 1 1 4 0 0 0 {}\n", full_layout);
+        for code in (32..127).chain(REQUIRED_CHARACTERS) {
+            let glyph = glyphs
+                .iter()
+                .find(|(c, _)| *c as u32 == code)
+                .map_or("  ", |(_, glyph)| glyph);
+            source.push_str(&format!("{}@@\n", glyph));
+        }
+        Font::parse(&source).unwrap()
+    }
+
+    /// `left` and `right` drawn next to each other with only `rules` on.
+    fn smushed(rules: u32, left: &str, right: &str) -> String {
+        font(SMUSHING | rules, &[('A', left), ('B', right)]).render("AB")[0].clone()
+    }
+
+    #[test]
+    fn full_width_keeps_every_column() {
+        assert_eq!(font(0, &[('A', "x "), ('B', " y")]).render("AB"), ["x  y"]);
+    }
+
+    #[test]
+    fn fitting_moves_glyphs_until_they_touch() {
+        let font = font(
+            FITTING,
+            &[('A', "x "), ('B', " y"), ('C', "x|"), ('D', "|y")],
+        );
+        assert_eq!(font.render("AB"), ["xy"]);
+        assert_eq!(font.render("CD"), ["x||y"]);
+        // The first glyph moves to the start of the line
+        assert_eq!(font.render("BA"), ["yx"]);
+    }
+
+    #[test]
+    fn equal_character_rule() {
+        assert_eq!(smushed(EQUAL_CHARACTER, "x|", "|y"), "x|y");
+        assert_eq!(smushed(UNDERSCORE, "x|", "|y"), "x||y");
+    }
+
+    #[test]
+    fn underscore_rule() {
+        assert_eq!(smushed(UNDERSCORE, "x_", "|y"), "x|y");
+        assert_eq!(smushed(UNDERSCORE, "x/", "_y"), "x/y");
+        assert_eq!(smushed(UNDERSCORE, "x_", "xy"), "x_xy");
+        assert_eq!(smushed(EQUAL_CHARACTER, "x_", "|y"), "x_|y");
+    }
+
+    #[test]
+    fn hierarchy_rule() {
+        assert_eq!(smushed(HIERARCHY, "x|", "/y"), "x/y");
+        assert_eq!(smushed(HIERARCHY, "x}", "|y"), "x}y");
+        assert_eq!(smushed(HIERARCHY, "x<", "(y"), "x<y");
+        // Both of a class stay apart
+        assert_eq!(smushed(HIERARCHY, "x[", "]y"), "x[]y");
+    }
+
+    #[test]
+    fn opposite_pair_rule() {
+        assert_eq!(smushed(OPPOSITE_PAIR, "x[", "]y"), "x|y");
+        assert_eq!(smushed(OPPOSITE_PAIR, "x}", "{y"), "x|y");
+        assert_eq!(smushed(OPPOSITE_PAIR, "x)", "(y"), "x|y");
+        assert_eq!(smushed(OPPOSITE_PAIR, "x<", ">y"), "x<>y");
+    }
+
+    #[test]
+    fn big_x_rule() {
+        assert_eq!(smushed(BIG_X, "x/", "\\y"), "x|y");
+        assert_eq!(smushed(BIG_X, "x\\", "/y"), "xYy");
+        assert_eq!(smushed(BIG_X, "x>", "<y"), "xXy");
+        assert_eq!(smushed(BIG_X, "x<", ">y"), "x<>y");
+    }
+
+    #[test]
+    fn hardblank_rule() {
+        assert_eq!(smushed(HARDBLANK, "x$", "$y"), "x y");
+        assert_eq!(smushed(EQUAL_CHARACTER, "x$", "$y"), "x  y");
+    }
+
+    #[test]
+    fn universal_smushing_lets_the_later_character_win() {
+        assert_eq!(smushed(0, "x|", "/y"), "x/y");
+        assert_eq!(smushed(0, "x|", "$y"), "x|y");
+    }
+
+    #[test]
+    fn narrow_glyphs_are_not_smushed() {
+        let font = font(SMUSHING | EQUAL_CHARACTER, &[('A', "|"), ('B', "|y")]);
+        assert_eq!(font.render("AB"), ["||y"]);
+    }
+
+    #[test]
+    fn every_bundled_font_parses() {
+        for (name, source) in FONTS {
+            let font = Font::parse(source).unwrap_or_else(|e| panic!("{}: {}", name, e));
+            assert_eq!(font.render("EMIV").len(), font.height, "{}", name);
+        }
+    }
+
+    /// Output of the `figlet` program, written by `tools/figlet-fixtures.sh`.
+    #[test]
+    fn matches_reference_figlet_output() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/figlet");
+        for (font_name, source) in FONTS {
+            let font = Font::parse(source).unwrap();
+            for (sample, text) in SAMPLES {
+                let path = fixtures.join(font_name).join(format!("{}.txt", sample));
+                let expected = fs::read_to_string(&path)
+                    .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+                let expected = expected.lines().map(str::trim_end).collect::<Vec<_>>();
+                assert_eq!(font.render(text), expected, "{} {}", font_name, sample);
+            }
+        }
+    }
+}
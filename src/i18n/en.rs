@@ -0,0 +1,111 @@
+use super::Message;
+
+pub const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+pub const SHORT_MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+pub const SHORT_DAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+pub fn message(message: Message) -> &'static str {
+    match message {
+        Message::AboutMe => "About Me",
+        Message::Projects => "Projects",
+        Message::Experiences => "Experiences",
+        Message::Posts => "Posts",
+        Message::Photos => "Photos",
+        Message::Ferris => ":)",
+        Message::Contact => "Contact",
+        Message::Whoami => "whoami",
+        Message::Education => "Education",
+        Message::Contributions => "Contributions",
+        Message::Publications => "Publications",
+        Message::Timeline => "Timeline",
+        Message::ContributionActivity => "Contribution activity",
+        Message::Links => "Links",
+        Message::FerrisArt => "Ferris, Ratatui and Unsafe Ferris",
+        Message::DetailsHint => "{} for details",
+        Message::ExpandOrTimelineHint => "{} to expand, {} for timeline",
+        Message::ReadHint => "{} to read",
+        Message::BackHint => "{} to go back",
+        Message::TimelineWork => "work",
+        Message::TimelineEducation => "education",
+        Message::ListHint => "{} for list",
+        Message::GalleryHint => "{} {} to browse, {} to switch mode",
+        Message::HalfBlockMode => "half-block",
+        Message::AsciiMode => "ASCII",
+        Message::BrailleMode => "braille",
+        Message::HelpHint => "press {} for help",
+        Message::ContactHint => "Scan the code or press {} to download contact.vcf",
+        Message::Status => "Status",
+        Message::Tech => "Tech",
+        Message::Crate => "Crate",
+        Message::Stars => "Stars",
+        Message::Repository => "Repository",
+        Message::Downloads => "{} downloads",
+        Message::StatsAsOf => "Stats as of {}",
+        Message::Active => "Active",
+        Message::Maintained => "Maintained",
+        Message::Finished => "Finished",
+        Message::Present => "Present",
+        Message::Year => "yr",
+        Message::Years => "yrs",
+        Message::Month => "mo",
+        Message::Months => "mos",
+        Message::NoPhotos => "No photos yet",
+        Message::KeyBindings => "Key bindings",
+        Message::Preset => "Preset: {}",
+        Message::Commands => "Commands",
+        Message::ContributionCount => "{} contributions",
+        Message::Less => "Less",
+        Message::More => "More",
+        Message::NoMatches => "no matches",
+        Message::NextTab => "Next tab",
+        Message::PreviousTab => "Previous tab",
+        Message::GoToTab => "Go to tab: {}",
+        Message::FocusNextPane => "Focus next pane",
+        Message::FocusPreviousPane => "Focus previous pane",
+        Message::ScrollDown => "Scroll down",
+        Message::ScrollUp => "Scroll up",
+        Message::HalfPageDown => "Scroll half a page down",
+        Message::HalfPageUp => "Scroll half a page up",
+        Message::PageDown => "Page down",
+        Message::PageUp => "Page up",
+        Message::ScrollToTop => "Scroll to top",
+        Message::ScrollToBottom => "Scroll to bottom",
+        Message::Activate => "Open or expand selected entry",
+        Message::Back => "Go back",
+        Message::ToggleTimeline => "Toggle timeline view",
+        Message::PreviousEntry => "Previous photo",
+        Message::NextEntry => "Next photo",
+        Message::CycleRenderMode => "Switch photo rendering mode",
+        Message::Search => "Search",
+        Message::NextMatch => "Next search match",
+        Message::PreviousMatch => "Previous search match",
+        Message::ClearSearch => "Clear search",
+        Message::OpenPalette => "Command palette",
+        Message::ToggleHelp => "Key bindings help",
+        Message::CycleKeymap => "Switch key binding preset",
+        Message::ToggleTheme => "Toggle theme",
+        Message::CycleLocale => "Switch language",
+        Message::ToggleAnimations => "Toggle animations",
+        Message::FocusNextLink => "Focus next link",
+        Message::OpenLink => "Open link: {}",
+        Message::DownloadResume => "Download résumé",
+        Message::DownloadContact => "Download contact card (.vcf)",
+    }
+}
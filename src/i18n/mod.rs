@@ -0,0 +1,276 @@
+use std::fmt::Display;
+
+mod en;
+mod tr;
+
+/// Language the interface and the content are shown in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    English,
+    Turkish,
+}
+
+/// Every locale with a catalog, in the order the switch key cycles through them.
+pub const LOCALES: &[Locale] = &[Locale::English, Locale::Turkish];
+
+impl Locale {
+    /// Locale of a language tag such as `tr-TR`, English when there is no catalog for it.
+    pub fn from_tag(tag: &str) -> Locale {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        LOCALES
+            .iter()
+            .copied()
+            .find(|locale| language.eq_ignore_ascii_case(locale.code()))
+            .unwrap_or_default()
+    }
+
+    /// Preferred language of the browser, from `navigator.language`.
+    pub fn detect() -> Locale {
+        web_sys::window()
+            .and_then(|window| window.navigator().language())
+            .map_or_else(Locale::default, |tag| Locale::from_tag(&tag))
+    }
+
+    pub fn next(self) -> Locale {
+        let index = LOCALES
+            .iter()
+            .position(|locale| *locale == self)
+            .unwrap_or(0);
+        LOCALES[(index + 1) % LOCALES.len()]
+    }
+
+    /// ISO 639-1 code of the language.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Turkish => "tr",
+        }
+    }
+
+    /// Text of a message in this locale's catalog.
+    pub fn message(&self, message: Message) -> &'static str {
+        match self {
+            Locale::English => en::message(message),
+            Locale::Turkish => tr::message(message),
+        }
+    }
+
+    /// A catalog message with `{}` replaced by `value`.
+    pub fn format(&self, message: Message, value: impl Display) -> String {
+        self.message(message).replacen("{}", &value.to_string(), 1)
+    }
+
+    /// Full name of a month, 1 being January.
+    pub fn month_name(&self, month: u32) -> &'static str {
+        let names = match self {
+            Locale::English => &en::MONTH_NAMES,
+            Locale::Turkish => &tr::MONTH_NAMES,
+        };
+        names[month as usize - 1]
+    }
+
+    /// Three-letter name of a month, 1 being January.
+    pub fn short_month_name(&self, month: u32) -> &'static str {
+        let names = match self {
+            Locale::English => &en::SHORT_MONTH_NAMES,
+            Locale::Turkish => &tr::SHORT_MONTH_NAMES,
+        };
+        names[month as usize - 1]
+    }
+
+    /// Three-letter name of a weekday, 0 being Sunday.
+    pub fn short_day_name(&self, weekday: u32) -> &'static str {
+        let names = match self {
+            Locale::English => &en::SHORT_DAY_NAMES,
+            Locale::Turkish => &tr::SHORT_DAY_NAMES,
+        };
+        names[weekday as usize % 7]
+    }
+}
+
+/// Interface text every catalog translates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Message {
+    AboutMe,
+    Projects,
+    Experiences,
+    Posts,
+    Photos,
+    Ferris,
+    Contact,
+    Whoami,
+    Education,
+    Contributions,
+    Publications,
+    Timeline,
+    ContributionActivity,
+    Links,
+    FerrisArt,
+    DetailsHint,          // `{}` is the key for details
+    ExpandOrTimelineHint, // `{}` are the keys to expand and for the timeline
+    ReadHint,             // `{}` is the key to read
+    BackHint,             // `{}` is the key to go back
+    TimelineWork,
+    TimelineEducation,
+    ListHint,    // `{}` is the key back to the list
+    GalleryHint, // `{}` are the keys to the previous and next photos, then the mode's
+    HalfBlockMode,
+    AsciiMode,
+    BrailleMode,
+    HelpHint,    // `{}` is the help key
+    ContactHint, // `{}` is the download key
+    Status,
+    Tech,
+    Crate,
+    Stars,
+    Repository,
+    Downloads, // `{}` is the count
+    StatsAsOf, // `{}` is the date
+    Active,
+    Maintained,
+    Finished,
+    Present,
+    Year,
+    Years,
+    Month,
+    Months,
+    NoPhotos,
+    KeyBindings,
+    Preset, // `{}` is the preset name
+    Commands,
+    ContributionCount, // `{}` is the total
+    Less,
+    More,
+    NoMatches,
+    // Labels of the actions
+    NextTab,
+    PreviousTab,
+    GoToTab, // `{}` is the tab title
+    FocusNextPane,
+    FocusPreviousPane,
+    ScrollDown,
+    ScrollUp,
+    HalfPageDown,
+    HalfPageUp,
+    PageDown,
+    PageUp,
+    ScrollToTop,
+    ScrollToBottom,
+    Activate,
+    Back,
+    ToggleTimeline,
+    PreviousEntry,
+    NextEntry,
+    CycleRenderMode,
+    Search,
+    NextMatch,
+    PreviousMatch,
+    ClearSearch,
+    OpenPalette,
+    ToggleHelp,
+    CycleKeymap,
+    ToggleTheme,
+    CycleLocale,
+    ToggleAnimations,
+    FocusNextLink,
+    OpenLink, // `{}` is the link name
+    DownloadResume,
+    DownloadContact,
+}
+
+/// Content text written in English, with translations where there are any.
+#[derive(Clone, Copy, Debug)]
+pub struct Localized {
+    pub en: &'static str,
+    pub tr: Option<&'static str>,
+}
+
+impl Localized {
+    pub const fn en(en: &'static str) -> Self {
+        Self { en, tr: None }
+    }
+
+    pub const fn tr(mut self, tr: &'static str) -> Self {
+        self.tr = Some(tr);
+        self
+    }
+
+    /// The text in `locale`, or in English when it has not been translated.
+    pub fn get(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::English => self.en,
+            Locale::Turkish => self.tr.unwrap_or(self.en),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Messages with `{}` in them, which every catalog has to keep.
+    const FORMATTED: &[Message] = &[
+        Message::DetailsHint,
+        Message::ExpandOrTimelineHint,
+        Message::ReadHint,
+        Message::BackHint,
+        Message::ListHint,
+        Message::GalleryHint,
+        Message::HelpHint,
+        Message::ContactHint,
+        Message::Downloads,
+        Message::StatsAsOf,
+        Message::Preset,
+        Message::ContributionCount,
+        Message::GoToTab,
+        Message::OpenLink,
+    ];
+
+    #[test]
+    fn tags_pick_a_catalog_by_language() {
+        assert_eq!(Locale::from_tag("tr-TR"), Locale::Turkish);
+        assert_eq!(Locale::from_tag("TR"), Locale::Turkish);
+        assert_eq!(Locale::from_tag("tr_CY"), Locale::Turkish);
+        assert_eq!(Locale::from_tag("en-GB"), Locale::English);
+        assert_eq!(Locale::from_tag("de-DE"), Locale::English);
+        assert_eq!(Locale::from_tag(""), Locale::English);
+    }
+
+    #[test]
+    fn next_cycles_through_every_locale() {
+        for (i, locale) in LOCALES.iter().enumerate() {
+            assert_eq!(locale.next(), LOCALES[(i + 1) % LOCALES.len()]);
+        }
+    }
+
+    #[test]
+    fn catalogs_keep_the_placeholders() {
+        for locale in LOCALES {
+            for message in FORMATTED {
+                let english = Locale::English.message(*message).matches("{}").count();
+                assert!(english > 0, "{:?}", message);
+                assert_eq!(
+                    locale.message(*message).matches("{}").count(),
+                    english,
+                    "{:?} {:?}",
+                    locale,
+                    message
+                );
+            }
+        }
+        assert_eq!(
+            Locale::Turkish.format(Message::HelpHint, "?"),
+            "yardım için ? tuşuna basın"
+        );
+    }
+
+    #[test]
+    fn calendar_names() {
+        assert_eq!(Locale::English.month_name(1), "January");
+        assert_eq!(Locale::Turkish.month_name(12), "Aralık");
+        assert_eq!(Locale::English.short_month_name(10), "Oct");
+        assert_eq!(Locale::English.short_day_name(0), "Sun");
+        assert_eq!(Locale::English.short_day_name(7), "Sun");
+    }
+}
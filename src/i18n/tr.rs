@@ -0,0 +1,101 @@
+use super::Message;
+
+pub const MONTH_NAMES: [&str; 12] = [
+    "Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül", "Ekim",
+    "Kasım", "Aralık",
+];
+
+pub const SHORT_MONTH_NAMES: [&str; 12] = [
+    "Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara",
+];
+
+pub const SHORT_DAY_NAMES: [&str; 7] = ["Paz", "Pzt", "Sal", "Çar", "Per", "Cum", "Cmt"];
+
+pub fn message(message: Message) -> &'static str {
+    match message {
+        Message::AboutMe => "Hakkımda",
+        Message::Projects => "Projeler",
+        Message::Experiences => "Deneyimler",
+        Message::Posts => "Yazılar",
+        Message::Photos => "Fotoğraflar",
+        Message::Ferris => ":)",
+        Message::Contact => "İletişim",
+        Message::Whoami => "whoami",
+        Message::Education => "Eğitim",
+        Message::Contributions => "Katkılar",
+        Message::Publications => "Yayınlar",
+        Message::Timeline => "Zaman çizelgesi",
+        Message::ContributionActivity => "Katkı etkinliği",
+        Message::Links => "Bağlantılar",
+        Message::FerrisArt => "Ferris, Ratatui ve Unsafe Ferris",
+        Message::DetailsHint => "{} ile ayrıntılar",
+        Message::ExpandOrTimelineHint => "{} ile aç, {} ile zaman çizelgesi",
+        Message::ReadHint => "{} ile oku",
+        Message::BackHint => "{} ile geri dön",
+        Message::TimelineWork => "iş",
+        Message::TimelineEducation => "eğitim",
+        Message::ListHint => "{} ile liste",
+        Message::GalleryHint => "{} {} ile gez, {} ile kip değiştir",
+        Message::HalfBlockMode => "yarım blok",
+        Message::AsciiMode => "ASCII",
+        Message::BrailleMode => "braille",
+        Message::HelpHint => "yardım için {} tuşuna basın",
+        Message::ContactHint => "Kodu tarayın ya da contact.vcf için {} tuşuna basın",
+        Message::Status => "Durum",
+        Message::Tech => "Teknolojiler",
+        Message::Crate => "Crate",
+        Message::Stars => "Yıldız",
+        Message::Repository => "Depo",
+        Message::Downloads => "{} indirme",
+        Message::StatsAsOf => "{} itibarıyla istatistikler",
+        Message::Active => "Aktif",
+        Message::Maintained => "Bakımı sürüyor",
+        Message::Finished => "Tamamlandı",
+        Message::Present => "Halen",
+        Message::Year => "yıl",
+        Message::Years => "yıl",
+        Message::Month => "ay",
+        Message::Months => "ay",
+        Message::NoPhotos => "Henüz fotoğraf yok",
+        Message::KeyBindings => "Tuş atamaları",
+        Message::Preset => "Ön ayar: {}",
+        Message::Commands => "Komutlar",
+        Message::ContributionCount => "{} katkı",
+        Message::Less => "Az",
+        Message::More => "Çok",
+        Message::NoMatches => "eşleşme yok",
+        Message::NextTab => "Sonraki sekme",
+        Message::PreviousTab => "Önceki sekme",
+        Message::GoToTab => "Sekmeye git: {}",
+        Message::FocusNextPane => "Sonraki bölmeye geç",
+        Message::FocusPreviousPane => "Önceki bölmeye geç",
+        Message::ScrollDown => "Aşağı kaydır",
+        Message::ScrollUp => "Yukarı kaydır",
+        Message::HalfPageDown => "Yarım sayfa aşağı kaydır",
+        Message::HalfPageUp => "Yarım sayfa yukarı kaydır",
+        Message::PageDown => "Sayfa aşağı",
+        Message::PageUp => "Sayfa yukarı",
+        Message::ScrollToTop => "En üste kaydır",
+        Message::ScrollToBottom => "En alta kaydır",
+        Message::Activate => "Seçili girdiyi aç ya da genişlet",
+        Message::Back => "Geri dön",
+        Message::ToggleTimeline => "Zaman çizelgesini aç ya da kapat",
+        Message::PreviousEntry => "Önceki fotoğraf",
+        Message::NextEntry => "Sonraki fotoğraf",
+        Message::CycleRenderMode => "Fotoğraf çizim kipini değiştir",
+        Message::Search => "Ara",
+        Message::NextMatch => "Sonraki arama sonucu",
+        Message::PreviousMatch => "Önceki arama sonucu",
+        Message::ClearSearch => "Aramayı temizle",
+        Message::OpenPalette => "Komut paleti",
+        Message::ToggleHelp => "Tuş atamaları yardımı",
+        Message::CycleKeymap => "Tuş atama ön ayarını değiştir",
+        Message::ToggleTheme => "Temayı değiştir",
+        Message::CycleLocale => "Dili değiştir",
+        Message::ToggleAnimations => "Animasyonları aç ya da kapat",
+        Message::FocusNextLink => "Sonraki bağlantıya geç",
+        Message::OpenLink => "Bağlantıyı aç: {}",
+        Message::DownloadResume => "Özgeçmişi indir",
+        Message::DownloadContact => "Kişi kartını indir (.vcf)",
+    }
+}
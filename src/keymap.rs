@@ -0,0 +1,305 @@
+use ratzilla::event::{KeyCode, KeyEvent};
+
+use crate::actions::Action;
+use crate::i18n::{Locale, Message};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Key {
+    pub code: KeyCode,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool, // Ignored for characters, which already carry their case
+}
+
+impl Key {
+    pub const fn plain(code: KeyCode) -> Self {
+        Self {
+            code,
+            ctrl: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub const fn shift(code: KeyCode) -> Self {
+        Self {
+            code,
+            ctrl: false,
+            alt: false,
+            shift: true,
+        }
+    }
+
+    pub const fn ctrl(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            ctrl: true,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub const fn alt(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            ctrl: false,
+            alt: true,
+            shift: false,
+        }
+    }
+
+    fn matches(&self, event: &KeyEvent) -> bool {
+        let shift_matches = matches!(self.code, KeyCode::Char(_)) || self.shift == event.shift;
+        self.code == event.code && self.ctrl == event.ctrl && self.alt == event.alt && shift_matches
+    }
+
+    /// Short label used by the help overlay and the tab bar hint.
+    pub fn label(&self) -> String {
+        let code = match &self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Unidentified => "?".to_string(),
+        };
+        match (self.ctrl, self.alt, self.shift) {
+            (true, _, _) => format!("C-{}", code),
+            (_, true, _) => format!("M-{}", code),
+            (_, _, true) => format!("S-{}", code),
+            _ => code,
+        }
+    }
+}
+
+pub struct Keymap {
+    pub name: &'static str,
+    pub bindings: Vec<(Key, Action)>,
+}
+
+impl Keymap {
+    /// Every preset, the first one being the default.
+    pub fn presets(tab_count: usize) -> Vec<Keymap> {
+        vec![
+            Self::vim(tab_count),
+            Self::emacs(tab_count),
+            Self::arrows(tab_count),
+        ]
+    }
+
+    pub fn vim(tab_count: usize) -> Self {
+        let mut bindings = arrow_bindings();
+        bindings.extend(letter_bindings(&[
+            ('h', Action::PreviousTab),
+            ('l', Action::NextTab),
+            ('j', Action::ScrollDown),
+            ('k', Action::ScrollUp),
+        ]));
+        bindings.extend([
+            (Key::plain(KeyCode::Char('g')), Action::ScrollToTop),
+            (Key::plain(KeyCode::Char('G')), Action::ScrollToBottom),
+            (Key::ctrl('d'), Action::HalfPageDown),
+            (Key::ctrl('u'), Action::HalfPageUp),
+        ]);
+        bindings.extend(common_bindings(tab_count));
+        Self {
+            name: "vim",
+            bindings,
+        }
+    }
+
+    /// Emacs chords as far as a browser lets a page have them.
+    ///
+    /// The site keeps its chords from the browser, but Ctrl-n (a new window),
+    /// Ctrl-t and Ctrl-w never reach a page, so `C-n` is left out and ↓
+    /// scrolls down instead.
+    pub fn emacs(tab_count: usize) -> Self {
+        let mut bindings = arrow_bindings();
+        bindings.extend([
+            (Key::ctrl('b'), Action::PreviousTab),
+            (Key::ctrl('f'), Action::NextTab),
+            (Key::ctrl('p'), Action::ScrollUp),
+            (Key::ctrl('v'), Action::PageDown),
+            (Key::alt('v'), Action::PageUp),
+            (Key::alt('<'), Action::ScrollToTop),
+            (Key::alt('>'), Action::ScrollToBottom),
+            (Key::ctrl('s'), Action::Search),
+            (Key::alt('x'), Action::OpenPalette),
+        ]);
+        bindings.extend(common_bindings(tab_count));
+        Self {
+            name: "emacs",
+            bindings,
+        }
+    }
+
+    pub fn arrows(tab_count: usize) -> Self {
+        let mut bindings = arrow_bindings();
+        bindings.extend(common_bindings(tab_count));
+        Self {
+            name: "arrows-only",
+            bindings,
+        }
+    }
+
+    pub fn action_for(&self, event: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(key, _)| key.matches(event))
+            .map(|(_, action)| *action)
+    }
+
+    /// Labels of the keys bound to `action`, skipping upper case duplicates.
+    pub fn keys_for(&self, action: Action) -> Vec<String> {
+        let bound: Vec<&Key> = self
+            .bindings
+            .iter()
+            .filter(|(_, a)| *a == action)
+            .map(|(key, _)| key)
+            .collect();
+        bound
+            .iter()
+            .filter(|key| match key.code {
+                KeyCode::Char(c) if c.is_uppercase() => !bound.iter().any(|other| {
+                    other.code == KeyCode::Char(c.to_ascii_lowercase()) && other.ctrl == key.ctrl
+                }),
+                _ => true,
+            })
+            .map(|key| key.label())
+            .collect()
+    }
+
+    /// Hint for a pair of opposite actions, e.g. `← h|l →`.
+    pub fn pair_hint(&self, first: Action, second: Action) -> String {
+        let mut second_keys = self.keys_for(second);
+        second_keys.reverse();
+        format!(
+            "{}|{}",
+            self.keys_for(first).join(" "),
+            second_keys.join(" ")
+        )
+    }
+
+    /// `message` with every `{}` filled in with the first key of the next of `actions`.
+    pub fn hint(&self, message: Message, actions: &[Action], locale: Locale) -> String {
+        actions
+            .iter()
+            .fold(locale.message(message).to_string(), |hint, action| {
+                let key = self
+                    .keys_for(*action)
+                    .into_iter()
+                    .next()
+                    .unwrap_or_default();
+                hint.replacen("{}", &key, 1)
+            })
+    }
+
+    /// Every bound action with its keys, in binding order.
+    pub fn help_entries(&self) -> Vec<(String, Action)> {
+        let mut actions: Vec<Action> = Vec::new();
+        for (_, action) in &self.bindings {
+            if !actions.contains(action) {
+                actions.push(*action);
+            }
+        }
+        actions
+            .into_iter()
+            .map(|action| (self.keys_for(action).join(" "), action))
+            .collect()
+    }
+}
+
+fn arrow_bindings() -> Vec<(Key, Action)> {
+    vec![
+        (Key::plain(KeyCode::Left), Action::PreviousTab),
+        (Key::plain(KeyCode::Right), Action::NextTab),
+        (Key::plain(KeyCode::Down), Action::ScrollDown),
+        (Key::plain(KeyCode::Up), Action::ScrollUp),
+        (Key::plain(KeyCode::PageDown), Action::PageDown),
+        (Key::plain(KeyCode::PageUp), Action::PageUp),
+        (Key::plain(KeyCode::Home), Action::ScrollToTop),
+        (Key::plain(KeyCode::End), Action::ScrollToBottom),
+        (Key::plain(KeyCode::Tab), Action::FocusNextPane),
+        (Key::shift(KeyCode::Tab), Action::FocusPreviousPane),
+    ]
+}
+
+/// Binds both cases of every letter, like the original hard-coded handler did.
+fn letter_bindings(letters: &[(char, Action)]) -> Vec<(Key, Action)> {
+    letters
+        .iter()
+        .flat_map(|(c, action)| {
+            [
+                (Key::plain(KeyCode::Char(*c)), *action),
+                (Key::plain(KeyCode::Char(c.to_ascii_uppercase())), *action),
+            ]
+        })
+        .collect()
+}
+
+/// Bindings shared by every preset.
+fn common_bindings(tab_count: usize) -> Vec<(Key, Action)> {
+    let mut bindings = vec![
+        (Key::plain(KeyCode::Enter), Action::Activate),
+        (Key::plain(KeyCode::Char('q')), Action::Back),
+        (Key::plain(KeyCode::Backspace), Action::Back),
+        (Key::plain(KeyCode::Char('t')), Action::ToggleTimeline),
+        (Key::plain(KeyCode::Char('[')), Action::PreviousEntry),
+        (Key::plain(KeyCode::Char(']')), Action::NextEntry),
+        (Key::plain(KeyCode::Char('m')), Action::CycleRenderMode),
+        (Key::plain(KeyCode::Char('i')), Action::CycleLocale),
+        (Key::plain(KeyCode::Char('f')), Action::FocusNextLink),
+        (Key::plain(KeyCode::Char('d')), Action::DownloadContact),
+        (Key::plain(KeyCode::Char('D')), Action::DownloadContact),
+        (Key::plain(KeyCode::Char('/')), Action::Search),
+        (Key::plain(KeyCode::Char('n')), Action::NextMatch),
+        (Key::plain(KeyCode::Char('N')), Action::PreviousMatch),
+        (Key::plain(KeyCode::Esc), Action::ClearSearch),
+        (Key::plain(KeyCode::Char(':')), Action::OpenPalette),
+        (Key::ctrl('k'), Action::OpenPalette),
+        (Key::ctrl('K'), Action::OpenPalette),
+        (Key::plain(KeyCode::Char('?')), Action::ToggleHelp),
+    ];
+    // Digits jump straight to the first nine tabs
+    bindings.extend((0..tab_count.min(9)).map(|tab| {
+        let digit = char::from_digit(tab as u32 + 1, 10).unwrap();
+        (Key::plain(KeyCode::Char(digit)), Action::GoToTab(tab))
+    }));
+    bindings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Chords browsers act on without telling the page.
+    const RESERVED: &[char] = &['n', 't', 'w'];
+
+    #[test]
+    fn no_preset_binds_a_reserved_chord() {
+        for keymap in Keymap::presets(7) {
+            for (key, action) in &keymap.bindings {
+                let reserved = matches!(key.code, KeyCode::Char(c)
+                    if key.ctrl && RESERVED.contains(&c.to_ascii_lowercase()));
+                assert!(
+                    !reserved,
+                    "{} binds {} to {:?}",
+                    keymap.name,
+                    key.label(),
+                    action
+                );
+            }
+        }
+    }
+}
@@ -1,24 +1,26 @@
 use gridlife::Grid;
 use ratatui::widgets::Tabs;
 use ratzilla::event::KeyCode;
+use ratzilla::ratatui::buffer::Buffer;
 use ratzilla::ratatui::layout::{Constraint, Flex, Layout, Rect};
-use ratzilla::ratatui::style::Style;
+use ratzilla::ratatui::style::{Style, Stylize};
 use ratzilla::ratatui::text::Line;
 use ratzilla::ratatui::widgets::Wrap;
 use ratzilla::ratatui::Frame;
 use ratzilla::ratatui::{
     layout::Alignment,
-    style::Color,
     widgets::{Block, Paragraph},
     Terminal,
 };
 use ratzilla::utils::is_mobile;
-use ratzilla::widgets::Hyperlink;
-use ratzilla::{DomBackend, WebRenderer};
+use ratzilla::WebRenderer;
 use std::cell::RefCell;
 use std::io;
 use std::rc::Rc;
-use tachyonfx::{Duration, EffectRenderer, Shader};
+use tachyonfx::{Duration, Effect, EffectRenderer, Shader};
+
+mod backend;
+use backend::{DomBackend, Hyperlink};
 
 mod data;
 use data::*;
@@ -32,7 +34,45 @@ use ui::*;
 mod animations;
 use animations::*;
 
-const TAB_TITLES: &[&str] = &["About Me", "Projects", "Experiences", ":)"];
+mod contact;
+
+mod search;
+use search::*;
+
+mod boot;
+use boot::Boot;
+
+mod screensaver;
+use screensaver::Backdrop;
+
+mod web;
+
+mod resume;
+
+mod actions;
+use actions::*;
+
+mod palette;
+
+mod keymap;
+
+mod tabs;
+use tabs::TabView;
+
+mod posts;
+
+mod photos;
+
+mod stats;
+
+mod date;
+
+mod art;
+
+mod figlet;
+
+mod i18n;
+use i18n::{Locale, Message};
 
 fn main() -> io::Result<()> {
     std::panic::set_hook(Box::new(console_error_panic_hook::hook));
@@ -40,37 +80,142 @@ fn main() -> io::Result<()> {
     let terminal = Terminal::new(backend)?;
     let size = terminal.size()?;
     let mut grid = Grid::new_random(size.width.into(), size.height.into());
+    let mut matrix_rain = MatrixRain::default();
+    let mut starfield = Starfield::default();
     let app_state = Rc::new(RefCell::new(AppState::new()));
+    // Links from the feeds point into the site, e.g. `#posts/<slug>`
+    if let Some(fragment) = web::location_hash() {
+        app_state.borrow_mut().follow_link(&fragment);
+    }
 
     // Define our effects
-    let mut content_effect = CREATE_CONTENT_EFFECT();
-    let mut banner_effect = CREATE_BANNER_EFFECT();
+    let effects = Effects::load();
+    let theme = app_state.borrow().theme(); // Effects pick up the theme at their next run
+    let mut content_transition = ContentTransition::enter(
+        &effects,
+        TransitionEffect::default(),
+        Direction::default(),
+        theme,
+    );
+    let mut content_snapshot = Buffer::empty(Rect::ZERO); // Content as drawn last frame
+    let mut banner_effect = effects.banner(theme);
+    let mut panel_effect: Option<Effect> = None; // The panel fading out or back in
+    let mut focus_effect = effects.focus(theme);
+    let mut focus_area = None; // Where the focus effect plays
+
+    web::on_mouse_move({
+        let app_state = app_state.clone();
+        move |cell| app_state.borrow_mut().pointer = cell
+    });
+
+    // Keys the site uses are not passed on to the browser's shortcuts
+    web::prevent_default_keys({
+        let app_state = app_state.clone();
+        move |event| app_state.borrow().handles_key(event)
+    });
 
     // Set up key event handling
     terminal.on_key_event({
         let app_state = app_state.clone();
         move |event| {
             let mut state = app_state.borrow_mut();
-            match event.code {
-                KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('L') => {
-                    state.next_tab();
-                }
-                KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('H') => {
-                    state.previous_tab();
-                }
-                KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
-                    state.scroll_down();
+            let state = &mut *state;
+            if state.boot.take().is_some() {
+                // Any key skips the boot sequence
+                return;
+            }
+            if state.screensaver.wake() {
+                // The key only brings the panel back
+                return;
+            }
+            if state.search.editing {
+                let tab_index = state.tab_index;
+                match event.code {
+                    // Chords are shortcuts, not text
+                    KeyCode::Char(_) if event.ctrl || event.alt => {}
+                    KeyCode::Char(c) => state.search.push(c, tab_index, &state.tabs, state.locale),
+                    KeyCode::Backspace => state.search.pop(tab_index, &state.tabs, state.locale),
+                    KeyCode::Enter => state.search.editing = false,
+                    KeyCode::Esc => state.search.close(),
+                    _ => {}
                 }
-                KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
-                    state.scroll_up();
+                return;
+            }
+            if state.palette.open {
+                let actions = Action::available(state);
+                let tab_titles = state.tab_titles();
+                let locale = state.locale;
+                if let Some(action) =
+                    state
+                        .palette
+                        .handle_key(&event, &actions, &tab_titles, locale)
+                {
+                    action.run(state);
                 }
-                _ => {}
+                return;
+            }
+            if state.help_open {
+                // Any key dismisses the help overlay
+                state.help_open = false;
+                return;
+            }
+            if state.tabs[state.tab_index].handle_key(&event) {
+                return;
+            }
+            let action = state.keymap().action_for(&event);
+            if let Some(action) = action.filter(|action| action.is_available(state)) {
+                action.run(state);
             }
         }
     });
 
     terminal.draw_web(move |frame| {
-        render_game_of_life(&mut grid, frame);
+        // First-time visitors see the machine boot before the site
+        {
+            let mut state = app_state.borrow_mut();
+            match state.boot.as_ref().map(Boot::screen) {
+                Some(Some(screen)) => {
+                    render_boot(frame, &screen);
+                    return;
+                }
+                Some(None) => state.boot = None,
+                None => {}
+            }
+        }
+        // Once the panel faded away, the screensaver has the whole screen
+        let (asleep, backdrop) = if is_mobile() {
+            (false, Backdrop::default())
+        } else {
+            let mut state = app_state.borrow_mut();
+            match state.screensaver.update() {
+                Some(true) => panel_effect = Some(effects.hide_panel(state.theme())),
+                Some(false) => {
+                    panel_effect = Some(effects.show_panel(state.theme()));
+                    banner_effect = effects.banner(state.theme());
+                }
+                None => {}
+            }
+            let fading =
+                state.animations_enabled && panel_effect.as_ref().is_some_and(Effect::running);
+            (
+                state.screensaver.is_active() && !fading,
+                state.screensaver.backdrop(),
+            )
+        };
+        match backdrop {
+            Backdrop::MatrixRain if asleep => {
+                matrix_rain.step(frame.area());
+                frame.render_widget(&matrix_rain, frame.area());
+            }
+            Backdrop::Starfield if asleep => {
+                starfield.step(frame.area());
+                frame.render_widget(&starfield, frame.area());
+            }
+            _ => render_game_of_life(&mut grid, frame),
+        }
+        if asleep {
+            return;
+        }
 
         let area = if is_mobile() {
             let vertical = Layout::vertical([Constraint::Percentage(30)]).flex(Flex::Center);
@@ -88,146 +233,218 @@ fn main() -> io::Result<()> {
 
         // Render appropriate view based on device
         if is_mobile() {
-            render_mobile_view(frame, area);
+            let state = app_state.borrow();
+            render_mobile_view(frame, area, state.theme(), state.locale);
         } else {
             let mut state = app_state.borrow_mut();
-            let tab_index = state.tab_index;
 
-            // Changing max scroll depending on the area.width
-            let tab_title = match area.width {
-                0..=50 => {
-                    for &i in &[0, 1] {
-                        state.update_max_scroll(i, 2);
-                    }
-                    state.update_max_scroll(2, 20);
-                    "<← h|l →, ↓ j|k ↑>"
-                }
-                51..=64 => {
-                    for &i in &[0, 1, 2, 3] {
-                        state.update_max_scroll(i, 0);
-                    }
-                    "<← h|l →>"
-                }
-                65..=100 => {
-                    for &i in &[0, 1] {
-                        state.update_max_scroll(i, 0);
-                    }
-                    state.update_max_scroll(2, 15);
-                    state.update_max_scroll(3, 116 - area.width);
-                    match tab_index {
-                        2 | 3 => "<← h|l →, ↓ j|k ↑>",
-                        _ => "<← h|l →>",
-                    }
-                }
-                101..=115 => {
-                    for &i in &[0, 1, 2] {
-                        state.update_max_scroll(i, 0);
-                    }
-                    state.update_max_scroll(2, 3);
-                    state.update_max_scroll(3, 116 - area.width);
-                    match tab_index {
-                        2 | 3 => "<← h|l →, ↓ j|k ↑>",
-                        _ => "<← h|l →>",
-                    }
-                }
-                _ => {
-                    for &i in &[0, 1, 3] {
-                        state.update_max_scroll(i, 0);
-                    }
-                    state.update_max_scroll(2, 3);
-                    if tab_index == 2 {
-                        "<← h|l →, ↓ j|k ↑>"
-                    } else {
-                        "<← h|l →>"
-                    }
-                }
-            };
+            // Bring the current search hit into view
+            let revealed_hit = state.search.take_reveal().filter(|hit| {
+                // Hits are refreshed when a layout changes, but never trust a stale one
+                hit.pane < state.tabs[hit.tab].pane_count()
+            });
+            if let Some(hit) = revealed_hit {
+                state.reveal(hit);
+            }
+            let tab_index = state.tab_index;
 
             // Check if we need to reset the animation
             if state.should_animate {
-                content_effect = CREATE_CONTENT_EFFECT();
+                content_transition = match state.left_tab.take() {
+                    Some((left_tab, direction)) => ContentTransition::replace(
+                        &effects,
+                        content_snapshot.clone(),
+                        state.tabs[left_tab].transition(direction),
+                        state.tabs[tab_index].transition(direction),
+                        direction,
+                        state.theme(),
+                    ),
+                    None => ContentTransition::enter(
+                        &effects,
+                        state.tabs[tab_index].transition(Direction::Next),
+                        Direction::Next,
+                        state.theme(),
+                    ),
+                };
                 state.should_animate = false;
             }
 
             let constraints = [
-                Constraint::Length(BANNER.lines().count() as u16 + 1), // Banner
-                Constraint::Length(3),                                 // Tabs
-                Constraint::Length(16),                                // Contents
-                Constraint::Length(LINKS.len() as u16 + 2),            // Links
+                Constraint::Length(block_height(BANNER.fit_width(area.width)) + 1), // Banner
+                Constraint::Length(3),                                              // Tabs
+                Constraint::Length(state.tabs[tab_index].content_height()),         // Contents
+                Constraint::Length(LINKS.len() as u16 + 2),                         // Links
             ];
-            render_background(frame, area, None, &constraints);
 
             // Split the area into sections
             let [banner_area, tabs_area, content_area, links_area] =
                 Layout::vertical(constraints).areas(area);
 
+            // Measure how far the current tab can scroll at this size
+            let (_, focused_pane) = state.focused();
+            let locale = state.locale;
+            let max_scroll = state.tabs[tab_index].max_scroll(content_area, locale);
+            state.update_max_scroll(tab_index, max_scroll);
+            state.page_height = state.tabs[tab_index].page_size(content_area, focused_pane);
+
+            // Scrolling to the hit has to wait for the scroll range of this size
+            let revealed_row = revealed_hit.and_then(|hit| {
+                let row = state.tabs[hit.tab].line_row(content_area, hit.pane, hit.line, locale)?;
+                Some((hit, row))
+            });
+            if let Some((hit, row)) = revealed_row {
+                let max_scroll = state.max_scroll[hit.tab]
+                    .get(hit.pane)
+                    .copied()
+                    .unwrap_or(0);
+                if let Some(position) = state.scroll_positions[hit.tab].get_mut(hit.pane) {
+                    *position = row.min(max_scroll);
+                }
+            }
+
+            // Hints are derived from whichever key binding preset is active
+            let keymap = state.keymap();
+            let mut hints = vec![keymap.pair_hint(Action::PreviousTab, Action::NextTab)];
+            if state.tabs[tab_index].pane_count() > 1 {
+                hints.push(keymap.pair_hint(Action::FocusPreviousPane, Action::FocusNextPane));
+            }
+            if state.max_scroll[tab_index][focused_pane] > 0 {
+                hints.push(keymap.pair_hint(Action::ScrollDown, Action::ScrollUp));
+            }
+            let tab_title = format!("<{}>", hints.join(", "));
+            let help_title = keymap
+                .keys_for(Action::ToggleHelp)
+                .first()
+                .map(|key| locale.format(Message::HelpHint, key));
+
+            let theme = state.theme();
+            render_background(frame, area, help_title, &constraints, theme);
+
             render_banner(frame, banner_area);
-            if banner_effect.running() {
+            if banner_effect.running() && state.animations_enabled {
                 frame.render_effect(&mut banner_effect, banner_area, Duration::from_millis(100));
             }
 
             // Render tabs
+            let tab_titles = state.tab_titles();
             let tabs = Tabs::new(
-                TAB_TITLES
+                tab_titles
                     .iter()
                     .map(|t| Line::from(*t))
                     .collect::<Vec<Line>>(),
             )
-            .block(
-                Block::bordered()
+            .block({
+                let block = Block::bordered()
                     .title_bottom(tab_title)
-                    .title_alignment(Alignment::Right),
-            )
+                    .title_alignment(Alignment::Right);
+                if state.search.is_active() {
+                    block.title_bottom(Line::from(state.search.status(locale)).left_aligned())
+                } else {
+                    block
+                }
+            })
             .select(tab_index)
-            .highlight_style(Style::default().fg(Color::Gray));
+            .highlight_style(Style::default().fg(theme.highlight));
             frame.render_widget(tabs, tabs_area);
 
-            // Render content based on selected tab
-            match tab_index {
-                0 => render_about_me_and_education(frame, content_area, state.scroll_positions[0]),
-                1 => render_projects_and_contributions(
+            // Render content of the selected tab
+            let view = TabView {
+                scroll: &state.scroll_positions[tab_index],
+                max_scroll: &state.max_scroll[tab_index],
+                focused_pane,
+                selection: &state.selections[tab_index],
+                theme,
+                locale,
+                keymap,
+            };
+            state.tabs[tab_index].render(frame, content_area, &view);
+            highlight_matches(frame.buffer_mut(), content_area, &state.search.query);
+            content_snapshot = snapshot(frame.buffer_mut(), content_area);
+            if state.animations_enabled {
+                content_transition.render(
                     frame,
                     content_area,
-                    state.scroll_positions[1],
-                ),
-                2 => render_experiences_and_publications(
+                    Duration::from_millis(100),
+                    theme.background,
+                );
+            }
+
+            render_links(frame, links_area, locale);
+
+            // Highlight what the mouse points at and the link in keyboard focus
+            let link_areas = link_areas(links_area);
+            let hovered = state.pointer.and_then(|pointer| {
+                tab_title_areas(tabs_area, &tab_titles)
+                    .into_iter()
+                    .chain(link_areas.iter().copied())
+                    .find(|area| area.contains(pointer))
+            });
+            let focused = state.focused_link.map(|link| link_areas[link]);
+            if let Some(area) = hovered {
+                frame
+                    .buffer_mut()
+                    .set_style(area, Style::default().underlined());
+            }
+            if let Some(area) = focused {
+                let style = Style::default().fg(theme.background).bg(theme.foreground);
+                frame.buffer_mut().set_style(area, style);
+            }
+            if focused.or(hovered) != focus_area {
+                focus_area = focused.or(hovered);
+                focus_effect = effects.focus(theme);
+            }
+            if let Some(area) = focus_area.filter(|_| state.animations_enabled) {
+                frame.render_effect(&mut focus_effect, area, Duration::from_millis(100));
+            }
+
+            if state.help_open {
+                render_help(
                     frame,
-                    content_area,
-                    state.scroll_positions[2],
-                ),
-                3 => render_ferris_ratatui_and_unsafe_ferris(
+                    area,
+                    state.keymap(),
+                    &tab_titles,
+                    theme,
+                    state.locale,
+                );
+            }
+            if state.palette.open {
+                render_command_palette(
                     frame,
-                    content_area,
-                    state.scroll_positions[3],
-                ),
-                _ => {}
+                    area,
+                    &state.palette,
+                    &Action::available(&state),
+                    &tab_titles,
+                    theme,
+                    state.locale,
+                );
+            }
+            if let Some(effect) = panel_effect
+                .as_mut()
+                .filter(|effect| effect.running() && state.animations_enabled)
+            {
+                let panel_area = panel_area(area, &constraints, frame.area());
+                frame.render_effect(effect, panel_area, Duration::from_millis(100));
             }
-            frame.render_effect(
-                &mut content_effect,
-                content_area,
-                Duration::from_millis(100),
-            );
-
-            render_links(frame, links_area);
         }
     });
 
     Ok(())
 }
 
-fn render_mobile_view(frame: &mut Frame, area: Rect) {
+fn render_mobile_view(frame: &mut Frame, area: Rect, theme: &Theme, locale: Locale) {
+    let info = MOBILE_INFO.get(locale);
     let constraints = [
-        Constraint::Length(MOBILE_INFO.lines().count() as u16 + 2),
+        Constraint::Length(block_height(info) + 2),
         Constraint::Length(1),
     ];
 
-    render_background(frame, area, None, &constraints);
+    render_background(frame, area, None, &constraints, theme);
 
     let [info_area, links_area] = Layout::vertical(constraints).areas(area);
 
     // Render mobile info message
     frame.render_widget(
-        Paragraph::new(MOBILE_INFO)
+        Paragraph::new(info)
             .wrap(Wrap { trim: true })
             .alignment(Alignment::Center),
         info_area,
@@ -0,0 +1,146 @@
+use ratzilla::event::{KeyCode, KeyEvent};
+
+use crate::actions::Action;
+use crate::i18n::Locale;
+
+#[derive(Default)]
+pub struct Palette {
+    pub(crate) open: bool,
+    pub(crate) query: String,
+    pub(crate) selected: usize,
+}
+
+impl Palette {
+    pub(crate) fn open(&mut self) {
+        *self = Self::default();
+        self.open = true;
+    }
+
+    pub(crate) fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Those of `actions` matching the query, best match first.
+    pub(crate) fn matches(
+        &self,
+        actions: &[Action],
+        tab_titles: &[&str],
+        locale: Locale,
+    ) -> Vec<Action> {
+        let mut scored: Vec<(i32, Action)> = actions
+            .iter()
+            .copied()
+            .filter_map(|action| {
+                fuzzy_score(&self.query, &action.label(tab_titles, locale)).map(|s| (s, action))
+            })
+            .collect();
+        // Stable sort keeps the registry order between equal scores
+        scored.sort_by_key(|(score, _)| -score);
+        scored.into_iter().map(|(_, action)| action).collect()
+    }
+
+    /// Handles a key press while the palette is open, returning the chosen action.
+    pub(crate) fn handle_key(
+        &mut self,
+        event: &KeyEvent,
+        actions: &[Action],
+        tab_titles: &[&str],
+        locale: Locale,
+    ) -> Option<Action> {
+        match event.code {
+            KeyCode::Esc => self.close(),
+            KeyCode::Enter => {
+                let action = self
+                    .matches(actions, tab_titles, locale)
+                    .get(self.selected)
+                    .copied();
+                self.close();
+                return action;
+            }
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                let last = self
+                    .matches(actions, tab_titles, locale)
+                    .len()
+                    .saturating_sub(1);
+                self.selected = (self.selected + 1).min(last);
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.selected = 0;
+            }
+            KeyCode::Char(c) if !event.ctrl => {
+                self.query.push(c);
+                self.selected = 0;
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+/// Scores `candidate` against `query` as a case-insensitive subsequence match.
+///
+/// Consecutive characters and matches at the start of a word score higher.
+/// Returns `None` when not every query character can be found in order.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = candidate[position..].iter().position(|c| *c == q)? + position;
+
+        score += 1;
+        if previous_match.is_some_and(|previous| previous + 1 == found) {
+            score += 5;
+        }
+        if found == 0 || !candidate[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        // Prefer matches close to where the previous one ended
+        score -= (found - position).min(5) as i32;
+
+        previous_match = Some(found);
+        position = found + 1;
+    }
+
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_and_word_start_matches_rank_higher() {
+        let prefix = fuzzy_score("next", "Next tab").unwrap();
+        let scattered = fuzzy_score("next", "Open exit text").unwrap();
+        assert!(prefix > scattered);
+
+        let word_starts = fuzzy_score("gt", "Go to tab").unwrap();
+        let inside = fuzzy_score("gt", "Length").unwrap();
+        assert!(word_starts > inside);
+    }
+
+    #[test]
+    fn missing_or_misordered_characters_do_not_match() {
+        assert_eq!(fuzzy_score("xyz", "Next tab"), None);
+        assert_eq!(fuzzy_score("bat", "Next tab"), None);
+    }
+
+    #[test]
+    fn case_and_spaces_are_ignored() {
+        assert_eq!(
+            fuzzy_score("NEXT TAB", "next tab"),
+            fuzzy_score("nexttab", "Next tab")
+        );
+    }
+
+    #[test]
+    fn an_empty_query_matches_everything() {
+        assert_eq!(fuzzy_score("", "Next tab"), Some(0));
+        assert_eq!(fuzzy_score("  ", ""), Some(0));
+    }
+}
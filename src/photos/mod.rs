@@ -0,0 +1,15 @@
+mod render;
+pub use render::*;
+
+use crate::i18n::Localized;
+
+/// A photo from `photos/gallery.json`, decoded by the build script.
+pub struct Photo {
+    pub caption: Localized,
+    pub width: u32,
+    pub height: u32,
+    pub pixels: &'static [u8], // RGB, row by row
+}
+
+/// Every photo, in gallery order.
+pub const PHOTOS: &[Photo] = include!(concat!(env!("OUT_DIR"), "/photos.rs"));
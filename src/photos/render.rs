@@ -0,0 +1,243 @@
+use ratzilla::ratatui::style::{Color, Style};
+use ratzilla::ratatui::text::{Line, Span, Text};
+
+use crate::i18n::Message;
+use crate::photos::Photo;
+
+/// How a photo is turned into terminal cells.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RenderMode {
+    /// `▀` with the upper pixel as foreground and the lower one as background.
+    #[default]
+    HalfBlock,
+    /// Characters of a luminance ramp, two pixels per cell.
+    Ascii,
+    /// Braille dots lit where a pixel is brighter than the photo's average.
+    Braille,
+}
+
+pub const RENDER_MODES: &[RenderMode] = &[
+    RenderMode::HalfBlock,
+    RenderMode::Ascii,
+    RenderMode::Braille,
+];
+
+/// Ramp from the darkest to the brightest character.
+const ASCII_RAMP: &[char] = &[' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+impl RenderMode {
+    pub fn next(self) -> RenderMode {
+        let index = RENDER_MODES
+            .iter()
+            .position(|mode| *mode == self)
+            .unwrap_or(0);
+        RENDER_MODES[(index + 1) % RENDER_MODES.len()]
+    }
+
+    pub fn label(&self) -> Message {
+        match self {
+            RenderMode::HalfBlock => Message::HalfBlockMode,
+            RenderMode::Ascii => Message::AsciiMode,
+            RenderMode::Braille => Message::BrailleMode,
+        }
+    }
+
+    /// Pixels a cell covers, across and down. Cells are about twice as tall as
+    /// they are wide, so these keep the pixels square.
+    fn cell_pixels(&self) -> (u32, u32) {
+        match self {
+            RenderMode::HalfBlock | RenderMode::Ascii => (1, 2),
+            RenderMode::Braille => (2, 4),
+        }
+    }
+}
+
+type Rgb = [u8; 3];
+
+/// The photo resampled to `width` × `height` pixels, each the average of the
+/// source pixels it covers.
+fn resample(photo: &Photo, width: u32, height: u32) -> Vec<Rgb> {
+    let mut pixels = Vec::with_capacity((width * height) as usize);
+    for y in 0..height {
+        let (top, bottom) = span(y, height, photo.height);
+        for x in 0..width {
+            let (left, right) = span(x, width, photo.width);
+            let mut sum = [0u32; 3];
+            for source_y in top..bottom {
+                for source_x in left..right {
+                    let i = ((source_y * photo.width + source_x) * 3) as usize;
+                    for (channel, total) in sum.iter_mut().enumerate() {
+                        *total += photo.pixels[i + channel] as u32;
+                    }
+                }
+            }
+            let count = (bottom - top) * (right - left);
+            pixels.push(sum.map(|total| (total / count) as u8));
+        }
+    }
+    pixels
+}
+
+/// Source pixels under target pixel `i` of `target`, never empty.
+fn span(i: u32, target: u32, source: u32) -> (u32, u32) {
+    let start = i * source / target;
+    let end = ((i + 1) * source / target).max(start + 1).min(source);
+    (start.min(source - 1), end)
+}
+
+fn luminance([r, g, b]: Rgb) -> f32 {
+    0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32
+}
+
+fn color([r, g, b]: Rgb) -> Color {
+    Color::Rgb(r, g, b)
+}
+
+/// The photo as terminal art fitting in `width` × `height` cells, keeping its
+/// aspect ratio.
+pub fn photo_text(photo: &Photo, width: u16, height: u16, mode: RenderMode) -> Text<'static> {
+    if photo.width == 0 || photo.height == 0 || width == 0 || height == 0 {
+        return Text::default();
+    }
+    let (cell_width, cell_height) = mode.cell_pixels();
+    let scale = f32::min(
+        (width as u32 * cell_width) as f32 / photo.width as f32,
+        (height as u32 * cell_height) as f32 / photo.height as f32,
+    );
+    let columns = ((photo.width as f32 * scale) as u32 / cell_width).max(1);
+    let rows = ((photo.height as f32 * scale) as u32 / cell_height).max(1);
+    let (pixel_width, pixel_height) = (columns * cell_width, rows * cell_height);
+    let pixels = resample(photo, pixel_width, pixel_height);
+    let pixel = |x: u32, y: u32| pixels[(y * pixel_width + x) as usize];
+
+    let average = pixels.iter().map(|p| luminance(*p)).sum::<f32>() / pixels.len() as f32;
+    let mut lines = Vec::with_capacity(rows as usize);
+    for row in 0..rows {
+        let top = row * cell_height;
+        let cells = (0..columns).map(|column| {
+            let x = column * cell_width;
+            match mode {
+                RenderMode::HalfBlock => Span::styled(
+                    "▀",
+                    Style::default()
+                        .fg(color(pixel(x, top)))
+                        .bg(color(pixel(x, top + 1))),
+                ),
+                RenderMode::Ascii => {
+                    let bright = (luminance(pixel(x, top)) + luminance(pixel(x, top + 1))) / 2.0;
+                    let step = (bright / 256.0 * ASCII_RAMP.len() as f32) as usize;
+                    Span::raw(ASCII_RAMP[step.min(ASCII_RAMP.len() - 1)].to_string())
+                }
+                RenderMode::Braille => {
+                    // Dot bits of the braille block, by column then row
+                    const DOTS: [[u32; 4]; 2] =
+                        [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+                    let mut bits = 0;
+                    let mut lit = Vec::new();
+                    for (dx, dots) in DOTS.iter().enumerate() {
+                        for (dy, bit) in dots.iter().enumerate() {
+                            let p = pixel(x + dx as u32, top + dy as u32);
+                            if luminance(p) > average {
+                                bits |= bit;
+                                lit.push(p);
+                            }
+                        }
+                    }
+                    let symbol = char::from_u32(0x2800 + bits).unwrap_or(' ').to_string();
+                    match average_color(&lit) {
+                        Some(rgb) => Span::styled(symbol, Style::default().fg(color(rgb))),
+                        None => Span::raw(symbol),
+                    }
+                }
+            }
+        });
+        lines.push(Line::from(cells.collect::<Vec<_>>()));
+    }
+    Text::from(lines)
+}
+
+fn average_color(pixels: &[Rgb]) -> Option<Rgb> {
+    if pixels.is_empty() {
+        return None;
+    }
+    let mut sum = [0u32; 3];
+    for pixel in pixels {
+        for (total, channel) in sum.iter_mut().zip(pixel) {
+            *total += *channel as u32;
+        }
+    }
+    Some(sum.map(|total| (total / pixels.len() as u32) as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Localized;
+
+    const WHITE: Rgb = [255, 255, 255];
+    const BLACK: Rgb = [0, 0, 0];
+    const RED: Rgb = [255, 0, 0];
+    const BLUE: Rgb = [0, 0, 255];
+
+    /// Two pixels across and four down, one color per row.
+    const PHOTO: Photo = Photo {
+        caption: Localized::en("rows"),
+        width: 2,
+        height: 4,
+        pixels: &[
+            255, 255, 255, 255, 255, 255, // White
+            0, 0, 0, 0, 0, 0, // Black
+            255, 0, 0, 255, 0, 0, // Red
+            0, 0, 255, 0, 0, 255, // Blue
+        ],
+    };
+
+    /// Symbol, foreground and background of a cell.
+    type Cell = (String, Option<Color>, Option<Color>);
+
+    /// Every cell, row by row.
+    fn cells(text: &Text<'_>) -> Vec<Vec<Cell>> {
+        text.lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| (span.content.to_string(), span.style.fg, span.style.bg))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn half_blocks_show_two_pixels_per_cell() {
+        let top = ("▀".to_string(), Some(color(WHITE)), Some(color(BLACK)));
+        let bottom = ("▀".to_string(), Some(color(RED)), Some(color(BLUE)));
+        assert_eq!(
+            cells(&photo_text(&PHOTO, 2, 2, RenderMode::HalfBlock)),
+            [[top.clone(), top], [bottom.clone(), bottom]]
+        );
+    }
+
+    #[test]
+    fn ascii_follows_the_luminance() {
+        let text = photo_text(&PHOTO, 2, 2, RenderMode::Ascii);
+        let rows: Vec<String> = text.lines.iter().map(|line| line.to_string()).collect();
+        assert_eq!(rows, ["==", ".."]);
+    }
+
+    #[test]
+    fn braille_lights_the_pixels_above_average() {
+        // Only the white row is brighter than the average
+        assert_eq!(
+            cells(&photo_text(&PHOTO, 1, 1, RenderMode::Braille)),
+            [[("⠉".to_string(), Some(color(WHITE)), None)]]
+        );
+    }
+
+    #[test]
+    fn photos_fit_the_pane_keeping_their_shape() {
+        let text = photo_text(&PHOTO, 10, 1, RenderMode::HalfBlock);
+        assert_eq!((text.lines.len(), text.lines[0].spans.len()), (1, 1));
+        assert!(photo_text(&PHOTO, 0, 4, RenderMode::Ascii).lines.is_empty());
+    }
+}
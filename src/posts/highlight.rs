@@ -0,0 +1,148 @@
+use ratzilla::ratatui::style::{Color, Style, Stylize};
+use ratzilla::ratatui::text::{Line, Span};
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+const SHELL_KEYWORDS: &[&str] = &[
+    "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if", "in",
+    "then", "while",
+];
+
+const TOML_KEYWORDS: &[&str] = &["false", "true"];
+
+/// Keywords and line comment marker of the languages code blocks can be tagged with.
+fn language(tag: &str) -> (&'static [&'static str], &'static str) {
+    match tag {
+        "rust" | "rs" => (RUST_KEYWORDS, "//"),
+        "sh" | "bash" | "shell" => (SHELL_KEYWORDS, "#"),
+        "toml" => (TOML_KEYWORDS, "#"),
+        _ => (&[], "//"),
+    }
+}
+
+/// Splits a line of code into styled spans: keywords, strings, numbers and comments.
+pub fn highlight_line(code: &str, tag: &str) -> Line<'static> {
+    let (keywords, comment) = language(tag);
+    let mut spans = Vec::new();
+    let mut plain = String::new(); // Unstyled text waiting to become one span
+    let mut rest = code;
+
+    while !rest.is_empty() {
+        if rest.starts_with(comment) {
+            flush(&mut spans, &mut plain);
+            spans.push(Span::styled(
+                rest.to_string(),
+                Style::default().fg(Color::DarkGray),
+            ));
+            break;
+        }
+
+        let first = rest.chars().next().unwrap();
+        let token_len = if first == '"' {
+            // Up to the closing quote, skipping escaped ones
+            let mut escaped = false;
+            rest[1..]
+                .char_indices()
+                .find(|(_, c)| {
+                    let closes = *c == '"' && !escaped;
+                    escaped = *c == '\\' && !escaped;
+                    closes
+                })
+                .map_or(rest.len(), |(i, _)| i + 2)
+        } else if first.is_alphanumeric() || first == '_' {
+            rest.find(|c: char| !c.is_alphanumeric() && c != '_')
+                .unwrap_or(rest.len())
+        } else {
+            first.len_utf8()
+        };
+
+        let token = &rest[..token_len];
+        let style = if first == '"' {
+            Some(Style::default().fg(Color::Green))
+        } else if first.is_ascii_digit() {
+            Some(Style::default().fg(Color::Magenta))
+        } else if keywords.contains(&token) {
+            Some(Style::default().fg(Color::Yellow).bold())
+        } else {
+            None
+        };
+        match style {
+            Some(style) => {
+                flush(&mut spans, &mut plain);
+                spans.push(Span::styled(token.to_string(), style));
+            }
+            None => plain.push_str(token),
+        }
+        rest = &rest[token_len..];
+    }
+    flush(&mut spans, &mut plain);
+
+    Line::from(spans)
+}
+
+fn flush(spans: &mut Vec<Span<'static>>, plain: &mut String) {
+    if !plain.is_empty() {
+        spans.push(Span::raw(std::mem::take(plain)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Text and foreground of every span of the highlighted line.
+    fn spans(code: &str, tag: &str) -> Vec<(String, Option<Color>)> {
+        highlight_line(code, tag)
+            .spans
+            .into_iter()
+            .map(|span| (span.content.into_owned(), span.style.fg))
+            .collect()
+    }
+
+    #[test]
+    fn keywords_strings_and_numbers() {
+        assert_eq!(
+            spans(r#"let s = "a\"b" + 42;"#, "rust"),
+            [
+                ("let".to_string(), Some(Color::Yellow)),
+                (" s = ".to_string(), None),
+                (r#""a\"b""#.to_string(), Some(Color::Green)),
+                (" + ".to_string(), None),
+                ("42".to_string(), Some(Color::Magenta)),
+                (";".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn comments_run_to_the_end_of_the_line() {
+        assert_eq!(
+            spans("fi # if done", "sh"),
+            [
+                ("fi".to_string(), Some(Color::Yellow)),
+                (" ".to_string(), None),
+                ("# if done".to_string(), Some(Color::DarkGray)),
+            ]
+        );
+    }
+
+    #[test]
+    fn keywords_only_count_in_their_language() {
+        assert_eq!(spans("let", "toml"), [("let".to_string(), None)]);
+        assert_eq!(spans("letter", "rust"), [("letter".to_string(), None)]);
+        assert_eq!(spans("", "rust"), []);
+    }
+
+    #[test]
+    fn unterminated_strings_run_to_the_end() {
+        assert_eq!(
+            spans("\"open", ""),
+            [("\"open".to_string(), Some(Color::Green))]
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Parser, Tag, TagEnd};
+use ratzilla::ratatui::style::{Color, Modifier, Style, Stylize};
+use ratzilla::ratatui::text::{Line, Span, Text};
+
+use crate::posts::highlight::highlight_line;
+
+/// Renders Markdown into styled text for a reader pane.
+///
+/// Headings, emphasis, lists, quotes, rules, links and code are supported;
+/// tables, images and raw HTML are shown as their plain text.
+pub fn markdown_text(markdown: &str) -> Text<'static> {
+    let mut renderer = Renderer::default();
+    for event in Parser::new(markdown) {
+        renderer.event(event);
+    }
+    renderer.flush();
+    // No trailing blank line under the last block
+    while renderer
+        .lines
+        .last()
+        .is_some_and(|line| line.spans.is_empty())
+    {
+        renderer.lines.pop();
+    }
+    Text::from(renderer.lines)
+}
+
+#[derive(Default)]
+struct Renderer {
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    styles: Vec<Style>,      // Innermost inline style last
+    lists: Vec<Option<u64>>, // Next number of every open list, `None` when bulleted
+    bullet: Option<String>,  // Marker of a list item that has not been written yet
+    quote_depth: usize,
+    code_block: Option<String>, // Language tag while inside a fenced block
+    links: Vec<String>,
+}
+
+impl Renderer {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => match &self.code_block {
+                Some(tag) => {
+                    let tag = tag.clone();
+                    for code in text.lines() {
+                        let mut line = highlight_line(code, &tag);
+                        line.spans.insert(0, Span::raw("  "));
+                        self.push_line(line);
+                    }
+                }
+                None => self.push_span(Span::styled(text.into_string(), self.style())),
+            },
+            Event::Code(code) => {
+                let style = self.style().fg(Color::Yellow);
+                self.push_span(Span::styled(code.into_string(), style));
+            }
+            Event::SoftBreak => self.push_span(Span::raw(" ")),
+            Event::HardBreak => self.flush(),
+            Event::Rule => {
+                self.flush();
+                self.push_line(Line::from("─".repeat(40)).dark_gray());
+                self.blank();
+            }
+            Event::TaskListMarker(done) => {
+                self.push_span(Span::raw(if done { "[x] " } else { "[ ] " }));
+            }
+            Event::Html(html) | Event::InlineHtml(html) => {
+                self.push_span(Span::raw(html.into_string()));
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush();
+                let style = match level {
+                    HeadingLevel::H1 => Style::default().bold().underlined(),
+                    HeadingLevel::H2 => Style::default().bold(),
+                    _ => Style::default().bold().italic(),
+                };
+                self.styles.push(style);
+            }
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.quote_depth += 1;
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush();
+                let tag = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().unwrap_or("").to_string()
+                    }
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code_block = Some(tag);
+            }
+            Tag::List(first) => {
+                self.flush();
+                self.lists.push(first);
+            }
+            Tag::Item => {
+                self.flush();
+                let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                self.bullet = Some(format!("{}{}", indent, marker));
+            }
+            Tag::Emphasis => self.styles.push(self.style().italic()),
+            Tag::Strong => self.styles.push(self.style().bold()),
+            Tag::Strikethrough => self.styles.push(self.style().crossed_out()),
+            Tag::Link { dest_url, .. } => {
+                self.styles.push(self.style().underlined());
+                self.links.push(dest_url.into_string());
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                self.flush();
+                // Tight list items stay together
+                if self.lists.is_empty() {
+                    self.blank();
+                }
+            }
+            TagEnd::Heading(_) => {
+                self.styles.pop();
+                self.flush();
+                self.blank();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.quote_depth -= 1;
+                if self.quote_depth == 0 {
+                    self.blank();
+                }
+            }
+            TagEnd::CodeBlock => {
+                self.code_block = None;
+                self.blank();
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank();
+                }
+            }
+            TagEnd::Item => self.flush(),
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.styles.pop();
+            }
+            TagEnd::Link => {
+                self.styles.pop();
+                let url = self.links.pop().unwrap_or_default();
+                // Autolinks already show their address
+                let shown = self.spans.last().is_some_and(|span| span.content == url);
+                if !shown {
+                    self.push_span(Span::raw(format!(" ({})", url)).dark_gray());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn style(&self) -> Style {
+        self.styles.last().copied().unwrap_or_default()
+    }
+
+    fn push_span(&mut self, span: Span<'static>) {
+        self.spans.push(span);
+    }
+
+    /// Ends the line being built, if any.
+    fn flush(&mut self) {
+        if !self.spans.is_empty() {
+            let line = Line::from(std::mem::take(&mut self.spans));
+            self.push_line(line);
+        }
+    }
+
+    /// Adds a finished line behind the quote bars and list indentation.
+    fn push_line(&mut self, mut line: Line<'static>) {
+        let mut prefix = Vec::new();
+        if self.quote_depth > 0 {
+            prefix.push(Span::raw("│ ".repeat(self.quote_depth)).dark_gray());
+            line = line.patch_style(Modifier::ITALIC);
+        }
+        match self.bullet.take() {
+            Some(bullet) => prefix.push(Span::raw(bullet)),
+            None if !self.lists.is_empty() => {
+                // Continuation lines line up with the item text
+                let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                prefix.push(Span::raw(format!("{}  ", indent)));
+            }
+            None => {}
+        }
+        line.spans.splice(0..0, prefix);
+        self.lines.push(line);
+    }
+
+    /// Separates blocks, never stacking two blank lines.
+    fn blank(&mut self) {
+        if self.lines.last().is_some_and(|line| !line.spans.is_empty()) {
+            self.lines.push(Line::default());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every line of `text` as plain text.
+    fn plain(text: &Text<'_>) -> Vec<String> {
+        text.lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn blocks_are_separated_by_one_blank_line() {
+        let text = markdown_text("# Title\n\nFirst\nparagraph.\n\n---\n\nLast.\n");
+        assert_eq!(
+            plain(&text),
+            [
+                "Title",
+                "",
+                "First paragraph.",
+                "",
+                &"─".repeat(40),
+                "",
+                "Last."
+            ]
+        );
+    }
+
+    #[test]
+    fn inline_styles_nest() {
+        let text = markdown_text("**bold *both*** `code`");
+        let spans = &text.lines[0].spans;
+        assert_eq!(spans[0].style, Style::default().bold());
+        assert_eq!(spans[1].style, Style::default().bold().italic());
+        assert_eq!(spans[3].content, "code");
+        assert_eq!(spans[3].style.fg, Some(Color::Yellow));
+    }
+
+    #[test]
+    fn lists_are_marked_and_indented() {
+        let text = markdown_text("1. one\n2. two\n   - nested\n\n- [x] done\n");
+        assert_eq!(
+            plain(&text),
+            ["1. one", "2. two", "  • nested", "", "• [x] done"]
+        );
+    }
+
+    #[test]
+    fn quotes_are_barred_and_italic() {
+        let text = markdown_text("> quoted\n> > twice\n");
+        assert_eq!(plain(&text), ["│ quoted", "", "│ │ twice"]);
+        assert!(text.lines[0].style.add_modifier.contains(Modifier::ITALIC));
+    }
+
+    #[test]
+    fn links_show_their_address_once() {
+        let text = markdown_text("[ratatui](https://ratatui.rs) <https://emiv.tr>");
+        assert_eq!(
+            plain(&text),
+            ["ratatui (https://ratatui.rs) https://emiv.tr"]
+        );
+    }
+
+    #[test]
+    fn code_blocks_are_indented_and_highlighted() {
+        let text = markdown_text("```rust\nlet x = 1;\n```\n");
+        assert_eq!(plain(&text), ["  let x = 1;"]);
+        assert_eq!(text.lines[0].spans[1].content, "let");
+        assert_eq!(text.lines[0].spans[1].style.fg, Some(Color::Yellow));
+    }
+}
@@ -0,0 +1,15 @@
+mod highlight;
+mod markdown;
+pub use markdown::*;
+
+/// A Markdown post from the `posts/` directory, embedded by the build script.
+pub struct Post {
+    pub slug: &'static str, // File name without `.md`, as in `#posts/<slug>` links
+    pub title: &'static str,
+    pub date: &'static str, // YYYY-MM-DD
+    pub tags: &'static [&'static str],
+    pub body: &'static str, // Markdown, front-matter stripped
+}
+
+/// Every post, newest first.
+pub const POSTS: &[Post] = include!(concat!(env!("OUT_DIR"), "/posts.rs"));
@@ -0,0 +1,77 @@
+use crate::data::*;
+use crate::i18n::Locale;
+use crate::ui::{duration_label, experiences_newest_first, stats_summary};
+
+/// Plain-text résumé assembled from the same data the tabs show.
+///
+/// It is always in English, whatever language the site is shown in.
+pub fn resume_text() -> String {
+    let locale = Locale::English;
+    let mut out = String::new();
+
+    out.push_str(&CONTACT.full_name());
+    out.push('\n');
+    out.push_str(CONTACT.email);
+    out.push('\n');
+    for (name, url) in CONTACT.socials.iter() {
+        out.push_str(&format!("{}: {}\n", name, url));
+    }
+
+    section(&mut out, "About");
+    out.push_str(DESCRIPTION.get(locale).trim());
+    out.push('\n');
+
+    section(&mut out, "Education");
+    for (university, field, gpa, duration) in EDUCATION.iter() {
+        out.push_str(&format!("- {}, {}, {}", university, field.get(locale), gpa));
+        if let Some(duration) = duration {
+            out.push_str(&format!(" ({})", duration_label(duration, locale)));
+        }
+        out.push('\n');
+    }
+
+    section(&mut out, "Experience");
+    for (company, title, duration, bullet_1, bullet_2) in experiences_newest_first() {
+        out.push_str(&format!(
+            "{}, {} ({})\n",
+            company,
+            title.get(locale),
+            duration_label(duration, locale)
+        ));
+        out.push_str(&format!(
+            "  - {}\n  - {}\n",
+            bullet_1.get(locale),
+            bullet_2.get(locale)
+        ));
+    }
+
+    section(&mut out, "Projects");
+    for project in PROJECTS.iter() {
+        out.push_str(&format!("- {}", project.name));
+        if let Some(status) = project.status {
+            out.push_str(&format!(" [{}]", status.label()));
+        }
+        out.push_str(&format!(": {}", project.description));
+        if let Some(stats) = stats_summary(project, locale) {
+            out.push_str(&format!(" ({})", stats));
+        }
+        out.push('\n');
+        out.push_str(&format!("  {}\n", project.repository));
+    }
+
+    section(&mut out, "Contributions");
+    for (project, description, commits) in CONTRIBUTIONS.iter() {
+        out.push_str(&format!("- {}, {}, {}\n", project, description, commits));
+    }
+
+    section(&mut out, "Publications");
+    for (paper, info) in PUBLICATIONS.iter() {
+        out.push_str(&format!("- {}\n  {}\n", paper, info));
+    }
+
+    out
+}
+
+fn section(out: &mut String, title: &str) {
+    out.push_str(&format!("\n{}\n{}\n", title, "=".repeat(title.len())));
+}
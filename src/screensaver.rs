@@ -0,0 +1,74 @@
+use web_time::{Duration, Instant};
+
+/// Time without a key press before the panel makes way for the screensaver.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// What fills the screen while the visitor is away, a different one each time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Backdrop {
+    #[default]
+    GameOfLife,
+    MatrixRain,
+    Starfield,
+}
+
+impl Backdrop {
+    fn next(self) -> Self {
+        match self {
+            Backdrop::GameOfLife => Backdrop::MatrixRain,
+            Backdrop::MatrixRain => Backdrop::Starfield,
+            Backdrop::Starfield => Backdrop::GameOfLife,
+        }
+    }
+}
+
+pub struct Screensaver {
+    last_key: Instant,
+    active: bool,
+    backdrop: Backdrop,
+    changed: bool, // Started or ended since the last update
+}
+
+impl Default for Screensaver {
+    fn default() -> Self {
+        Self {
+            last_key: Instant::now(),
+            active: false,
+            backdrop: Backdrop::default(),
+            changed: false,
+        }
+    }
+}
+
+impl Screensaver {
+    /// Records a key press, returning `true` when it ended the screensaver and
+    /// should do nothing else.
+    pub(crate) fn wake(&mut self) -> bool {
+        self.last_key = Instant::now();
+        if !self.active {
+            return false;
+        }
+        self.active = false;
+        self.backdrop = self.backdrop.next();
+        self.changed = true;
+        true
+    }
+
+    /// Starts the screensaver once the visitor has been idle long enough.
+    /// Returns whether it started (`true`) or ended (`false`) since the last call.
+    pub(crate) fn update(&mut self) -> Option<bool> {
+        if !self.active && self.last_key.elapsed() >= IDLE_TIMEOUT {
+            self.active = true;
+            self.changed = true;
+        }
+        std::mem::take(&mut self.changed).then_some(self.active)
+    }
+
+    pub(crate) fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub(crate) fn backdrop(&self) -> Backdrop {
+        self.backdrop
+    }
+}
@@ -0,0 +1,232 @@
+use ratzilla::ratatui::buffer::Buffer;
+use ratzilla::ratatui::layout::Rect;
+use ratzilla::ratatui::style::{Color, Style};
+use ratzilla::ratatui::text::{Line, Text};
+use unicode_width::UnicodeWidthStr;
+
+use crate::i18n::{Locale, Message};
+use crate::tabs::Tab;
+use crate::ui::wrapped_height;
+
+const MATCH_STYLE: Style = Style::new().fg(Color::Black).bg(Color::Rgb(250, 200, 60));
+
+#[derive(Clone, Copy)]
+pub struct SearchHit {
+    pub tab: usize,
+    pub pane: usize, // A list pane selects the entry of the line instead of scrolling
+    pub line: usize,
+}
+
+#[derive(Default)]
+pub struct Search {
+    pub(crate) query: String,
+    pub(crate) editing: bool,
+    hits: Vec<SearchHit>,
+    current: usize,
+    reveal: bool,
+}
+
+impl Search {
+    pub(crate) fn open(&mut self) {
+        *self = Self::default();
+        self.editing = true;
+    }
+
+    pub(crate) fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    pub(crate) fn is_active(&self) -> bool {
+        self.editing || !self.query.is_empty()
+    }
+
+    pub(crate) fn push(&mut self, c: char, from_tab: usize, tabs: &[Box<dyn Tab>], locale: Locale) {
+        self.query.push(c);
+        self.update(from_tab, tabs, locale);
+    }
+
+    pub(crate) fn pop(&mut self, from_tab: usize, tabs: &[Box<dyn Tab>], locale: Locale) {
+        self.query.pop();
+        self.update(from_tab, tabs, locale);
+    }
+
+    pub(crate) fn next(&mut self) {
+        if !self.hits.is_empty() {
+            self.current = (self.current + 1) % self.hits.len();
+            self.reveal = true;
+        }
+    }
+
+    pub(crate) fn previous(&mut self) {
+        if !self.hits.is_empty() {
+            self.current = (self.current + self.hits.len() - 1) % self.hits.len();
+            self.reveal = true;
+        }
+    }
+
+    /// Returns the current hit once after it changed, so it can be scrolled into view.
+    pub(crate) fn take_reveal(&mut self) -> Option<SearchHit> {
+        if !std::mem::take(&mut self.reveal) {
+            return None;
+        }
+        self.hits.get(self.current).copied()
+    }
+
+    pub(crate) fn status(&self, locale: Locale) -> String {
+        let cursor = if self.editing { "▏" } else { "" };
+        match self.hits.len() {
+            0 if self.query.is_empty() => format!("/{}", cursor),
+            0 => format!(
+                "/{}{} [{}]",
+                self.query,
+                cursor,
+                locale.message(Message::NoMatches)
+            ),
+            n => format!("/{}{} [{}/{}]", self.query, cursor, self.current + 1, n),
+        }
+    }
+
+    /// Finds the hits again after the texts changed, e.g. when a tab opened a
+    /// detail view, so that none points at a pane or line that is gone.
+    pub(crate) fn refresh(&mut self, tabs: &[Box<dyn Tab>], locale: Locale) {
+        if self.query.is_empty() {
+            return;
+        }
+        self.hits = find_hits(&self.query, tabs, locale);
+        self.current = self.current.min(self.hits.len().saturating_sub(1));
+    }
+
+    fn update(&mut self, from_tab: usize, tabs: &[Box<dyn Tab>], locale: Locale) {
+        self.hits = find_hits(&self.query, tabs, locale);
+        // Incremental matches start from the tab the visitor is looking at
+        self.current = self
+            .hits
+            .iter()
+            .position(|hit| hit.tab >= from_tab)
+            .unwrap_or(0);
+        self.reveal = !self.hits.is_empty();
+    }
+}
+
+fn find_hits(query: &str, tabs: &[Box<dyn Tab>], locale: Locale) -> Vec<SearchHit> {
+    let query = query.to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hits = Vec::new();
+    for (tab, content) in tabs.iter().enumerate() {
+        for (pane, text) in content.searchable_texts(locale).iter().enumerate() {
+            for (line, content) in text.lines.iter().enumerate() {
+                if line_to_string(content).to_lowercase().contains(&query) {
+                    hits.push(SearchHit { tab, pane, line });
+                }
+            }
+        }
+    }
+    hits
+}
+
+/// Row a line of `text` starts at once wrapped to `width` columns.
+pub fn wrapped_row(text: &Text<'_>, line: usize, width: u16) -> u16 {
+    let before = Text::from(text.lines[..line.min(text.lines.len())].to_vec());
+    wrapped_height(&before, width)
+}
+
+/// Restyles every occurrence of `query` that is already drawn inside `area`.
+pub fn highlight_matches(buffer: &mut Buffer, area: Rect, query: &str) {
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    if query.is_empty() {
+        return;
+    }
+
+    let area = area.intersection(buffer.area);
+    for y in area.top()..area.bottom() {
+        // Wide characters are followed by a blank cell that is not part of the text
+        let mut row: Vec<(u16, Vec<char>)> = Vec::new();
+        let mut x = area.left();
+        while x < area.right() {
+            let symbol = buffer[(x, y)].symbol();
+            row.push((x, symbol.to_lowercase().chars().collect()));
+            x += symbol.width().max(1) as u16;
+        }
+
+        for start in 0..row.len() {
+            if let Some(len) = match_len(&row[start..], &query) {
+                for (x, _) in &row[start..start + len] {
+                    buffer[(*x, y)].set_style(MATCH_STYLE);
+                }
+            }
+        }
+    }
+}
+
+/// Number of cells at the start of `row` that spell out `query`.
+fn match_len(row: &[(u16, Vec<char>)], query: &[char]) -> Option<usize> {
+    let mut rest = query;
+    for (cells, (_, symbol)) in row.iter().enumerate() {
+        if rest.is_empty() {
+            return Some(cells);
+        }
+        if symbol.is_empty() || !rest.starts_with(symbol) {
+            return None;
+        }
+        rest = &rest[symbol.len()..];
+    }
+    rest.is_empty().then_some(row.len())
+}
+
+fn line_to_string(line: &Line<'_>) -> String {
+    line.spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::PROJECTS;
+    use crate::tabs::{registry, ListSelection, TabContext};
+
+    #[test]
+    fn refreshed_hits_follow_a_changed_layout() {
+        let mut tabs = registry();
+        let mut search = Search::default();
+        search.open();
+        search.push('e', 0, &tabs, Locale::English);
+        let projects = 1;
+        assert!(search
+            .hits
+            .iter()
+            .any(|hit| hit.tab == projects && hit.pane == 1));
+
+        // The detail view has a single pane
+        let mut cx = TabContext {
+            focused_pane: 0,
+            scroll: &mut [0, 0],
+            selection: &mut ListSelection::default(),
+        };
+        assert!(tabs[projects].open(&PROJECTS[0].name.to_lowercase(), &mut cx));
+        search.refresh(&tabs, Locale::English);
+        for hit in &search.hits {
+            assert!(hit.pane < tabs[hit.tab].pane_count());
+        }
+    }
+
+    #[test]
+    fn list_hits_select_and_expand_their_entry() {
+        let mut tabs = registry();
+        let experiences = 2;
+        let mut selection = ListSelection::default();
+        let mut cx = TabContext {
+            focused_pane: 0,
+            scroll: &mut [0, 0],
+            selection: &mut selection,
+        };
+        // The first bullet of the second experience
+        tabs[experiences].reveal(0, 5, &mut cx);
+        assert_eq!(selection.selected, 1);
+        assert!(selection.is_expanded(1));
+    }
+}
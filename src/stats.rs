@@ -0,0 +1,55 @@
+use crate::data::Project;
+use crate::date::Date;
+
+/// A crate's numbers on crates.io when the snapshot was taken.
+pub struct CrateStats {
+    pub name: &'static str,
+    pub downloads: u64,
+    pub version: &'static str, // Latest stable release
+}
+
+/// A repository's numbers on GitHub when the snapshot was taken.
+pub struct RepositoryStats {
+    pub path: &'static str, // owner/name
+    pub stars: u64,
+}
+
+// STATS_AS_OF, CRATE_STATS and REPOSITORY_STATS, from `stats/snapshot.json` if there is one
+include!(concat!(env!("OUT_DIR"), "/stats.rs"));
+
+// CONTRIBUTIONS_FROM and CONTRIBUTION_COUNTS, from `stats/contributions.json` if there is one
+include!(concat!(env!("OUT_DIR"), "/contributions.rs"));
+
+/// Day of the first contribution count, `None` without contributions.
+pub fn contributions_start() -> Option<Date> {
+    // The build script already checked the date
+    CONTRIBUTIONS_FROM.map(|from| Date::parse(from).unwrap())
+}
+
+pub fn crate_stats(project: &Project) -> Option<&'static CrateStats> {
+    let name = project.crate_name?;
+    CRATE_STATS.iter().find(|stats| stats.name == name)
+}
+
+pub fn repository_stats(project: &Project) -> Option<&'static RepositoryStats> {
+    let path = project
+        .repository
+        .strip_prefix("https://github.com/")?
+        .trim_end_matches('/');
+    REPOSITORY_STATS
+        .iter()
+        .find(|stats| stats.path.eq_ignore_ascii_case(path))
+}
+
+/// `25412` as `25,412`.
+pub fn thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(digit);
+    }
+    out
+}
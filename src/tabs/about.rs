@@ -0,0 +1,44 @@
+use ratzilla::ratatui::layout::{Constraint, Rect};
+use ratzilla::ratatui::text::Text;
+use ratzilla::ratatui::Frame;
+
+use crate::i18n::{Locale, Message};
+use crate::tabs::{Tab, TabView};
+use crate::ui::{education_text, render_text_panes, text_pane_areas, whoami_text, TextPane};
+
+const PANES: &[TextPane] = &[
+    TextPane {
+        title: Message::Whoami,
+        text: whoami_text,
+        constraint: Constraint::Fill(1),
+    },
+    TextPane {
+        title: Message::Education,
+        text: education_text,
+        constraint: Constraint::Length(6),
+    },
+];
+
+pub struct AboutTab;
+
+impl Tab for AboutTab {
+    fn title(&self) -> Message {
+        Message::AboutMe
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, view: &TabView) {
+        render_text_panes(frame, area, PANES, view);
+    }
+
+    fn pane_count(&self) -> usize {
+        PANES.len()
+    }
+
+    fn pane_areas(&self, area: Rect) -> Vec<Rect> {
+        text_pane_areas(area, PANES)
+    }
+
+    fn searchable_texts(&self, locale: Locale) -> Vec<Text<'static>> {
+        PANES.iter().map(|pane| (pane.text)(locale)).collect()
+    }
+}
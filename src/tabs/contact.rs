@@ -0,0 +1,54 @@
+use ratzilla::ratatui::layout::Rect;
+use ratzilla::ratatui::text::{Line, Text};
+use ratzilla::ratatui::Frame;
+
+use crate::animations::{Direction, TransitionEffect};
+use crate::data::CONTACT;
+use crate::i18n::{Locale, Message};
+use crate::tabs::{Tab, TabView, DEFAULT_CONTENT_HEIGHT};
+use crate::ui::{contact_areas, contact_card_text, render_contact};
+
+pub struct ContactTab {
+    qr: Vec<Line<'static>>, // Encoding is too slow to redo every frame
+}
+
+impl ContactTab {
+    pub fn new() -> Self {
+        Self {
+            qr: CONTACT.qr_lines(),
+        }
+    }
+}
+
+impl Tab for ContactTab {
+    fn title(&self) -> Message {
+        Message::Contact
+    }
+
+    /// Dissolves when reached from the tabs before it, slides back into them
+    fn transition(&self, direction: Direction) -> TransitionEffect {
+        match direction {
+            Direction::Next => TransitionEffect::Dissolve,
+            Direction::Previous => TransitionEffect::Slide,
+        }
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, view: &TabView) {
+        render_contact(frame, area, &self.qr, view);
+    }
+
+    /// The QR code and the hint under it need more room than the other tabs
+    fn content_height(&self) -> u16 {
+        (self.qr.len() as u16 + 1).max(DEFAULT_CONTENT_HEIGHT)
+    }
+
+    /// Only the contact card is a pane, the QR code never scrolls
+    fn pane_areas(&self, area: Rect) -> Vec<Rect> {
+        let [_, card_area] = contact_areas(area, &self.qr);
+        vec![card_area]
+    }
+
+    fn searchable_texts(&self, _locale: Locale) -> Vec<Text<'static>> {
+        vec![contact_card_text()]
+    }
+}
@@ -0,0 +1,132 @@
+use ratzilla::ratatui::layout::{Constraint, Rect};
+use ratzilla::ratatui::text::Text;
+use ratzilla::ratatui::widgets::ScrollbarOrientation;
+use ratzilla::ratatui::Frame;
+
+use crate::actions::Action;
+use crate::animations::{Direction, TransitionEffect};
+use crate::data::EXPERIENCES;
+use crate::i18n::{Locale, Message};
+use crate::tabs::{Tab, TabContext, TabView};
+use crate::ui::{
+    experience_items, experiences_text, list_text_width, publications_text, render_list_pane,
+    render_scrollbar, render_text_pane, render_timeline_pane, text_max_scroll, text_pane_areas,
+    TextPane,
+};
+
+const PANES: &[TextPane] = &[
+    TextPane {
+        title: Message::Experiences,
+        text: experiences_text,
+        constraint: Constraint::Fill(1),
+    },
+    TextPane {
+        title: Message::Publications,
+        text: publications_text,
+        constraint: Constraint::Length(6),
+    },
+];
+
+/// Experiences are a list, Enter shows or hides what was done at the selected one.
+/// `t` swaps the list for a timeline of experiences and education.
+#[derive(Default)]
+pub struct ExperiencesTab {
+    timeline: bool,
+}
+
+impl Tab for ExperiencesTab {
+    fn title(&self) -> Message {
+        Message::Experiences
+    }
+
+    fn transition(&self, _direction: Direction) -> TransitionEffect {
+        TransitionEffect::Slide
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, view: &TabView) {
+        let [list_area, publications_area] = self.pane_areas(area)[..] else {
+            return;
+        };
+        if self.timeline {
+            render_timeline_pane(frame, list_area, view);
+        } else {
+            render_list_pane(
+                frame,
+                list_area,
+                view.locale.message(PANES[0].title),
+                &view.keymap.hint(
+                    Message::ExpandOrTimelineHint,
+                    &[Action::Activate, Action::ToggleTimeline],
+                    view.locale,
+                ),
+                experience_items(view.selection, list_text_width(list_area), view.locale),
+                view.focused_pane == 0,
+                view,
+            );
+        }
+        render_text_pane(
+            frame,
+            publications_area,
+            view.locale.message(PANES[1].title),
+            (PANES[1].text)(view.locale),
+            view.scroll[1],
+            view.focused_pane == 1,
+            view.theme,
+        );
+        render_scrollbar(
+            frame,
+            publications_area,
+            view.scroll[1],
+            view.max_scroll[1],
+            ScrollbarOrientation::VerticalRight,
+        );
+    }
+
+    fn pane_count(&self) -> usize {
+        PANES.len()
+    }
+
+    fn pane_areas(&self, area: Rect) -> Vec<Rect> {
+        text_pane_areas(area, PANES)
+    }
+
+    /// The list moves its selection instead of scrolling
+    fn max_scroll(&self, area: Rect, locale: Locale) -> Vec<u16> {
+        let publications_area = self.pane_areas(area)[1];
+        vec![
+            0,
+            text_max_scroll(&(PANES[1].text)(locale), publications_area),
+        ]
+    }
+
+    fn handle_action(&mut self, action: Action, cx: &mut TabContext) -> bool {
+        if action == Action::ToggleTimeline {
+            self.timeline = !self.timeline;
+            return true;
+        }
+        if cx.focused_pane != 0 || self.timeline {
+            return false;
+        }
+        match action {
+            Action::Activate => {
+                cx.selection.toggle_expanded();
+                true
+            }
+            _ => cx.selection.navigate(action, EXPERIENCES.len()),
+        }
+    }
+
+    /// Every experience is four lines of the list's text: the role, two bullets
+    /// and a blank line
+    fn reveal(&mut self, pane: usize, line: usize, cx: &mut TabContext) {
+        if pane == 0 {
+            self.timeline = false;
+            cx.selection.selected = line / 4;
+            cx.selection.expand(line / 4);
+        }
+    }
+
+    fn searchable_texts(&self, locale: Locale) -> Vec<Text<'static>> {
+        PANES.iter().map(|pane| (pane.text)(locale)).collect()
+    }
+}
@@ -0,0 +1,41 @@
+use ratzilla::ratatui::layout::Rect;
+use ratzilla::ratatui::widgets::Block;
+use ratzilla::ratatui::Frame;
+
+use crate::animations::{Direction, TransitionEffect};
+use crate::data::FERRIS_ART;
+use crate::i18n::{Locale, Message};
+use crate::tabs::{Tab, TabView};
+use crate::ui::{art_max_scroll, render_ferris_ratatui_and_unsafe_ferris};
+
+/// Shows the largest variant of the art that fits, scrolling sideways when none does.
+pub struct FerrisTab;
+
+impl Tab for FerrisTab {
+    fn title(&self) -> Message {
+        Message::Ferris
+    }
+
+    fn transition(&self, _direction: Direction) -> TransitionEffect {
+        TransitionEffect::Glitch
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, view: &TabView) {
+        render_ferris_ratatui_and_unsafe_ferris(
+            frame,
+            area,
+            view.scroll[0],
+            view.max_scroll[0],
+            view.locale,
+        );
+    }
+
+    /// Only scrolls when even the smallest variant has to be cropped
+    fn max_scroll(&self, area: Rect, _locale: Locale) -> Vec<u16> {
+        vec![art_max_scroll(&FERRIS_ART, Block::bordered().inner(area))]
+    }
+
+    fn page_size(&self, area: Rect, _pane: usize) -> u16 {
+        area.width.saturating_sub(2)
+    }
+}
@@ -0,0 +1,85 @@
+use std::cell::RefCell;
+
+use ratzilla::ratatui::layout::Rect;
+use ratzilla::ratatui::text::Text;
+use ratzilla::ratatui::Frame;
+
+use crate::actions::Action;
+use crate::animations::{Direction, TransitionEffect};
+use crate::i18n::{Locale, Message};
+use crate::photos::{photo_text, RenderMode, PHOTOS};
+use crate::tabs::{Tab, TabContext, TabView, DEFAULT_CONTENT_HEIGHT};
+use crate::ui::{photo_pane_inner, render_photo_pane};
+
+/// One photo at a time, resized to the pane. `[` and `]` browse, `m` switches between
+/// half-block, ASCII and braille rendering.
+#[derive(Default)]
+pub struct GalleryTab {
+    photo: usize,
+    mode: RenderMode,
+    text: RefCell<Option<(PhotoKey, Text<'static>)>>, // Drawing is too slow to redo every frame
+}
+
+/// What the drawn photo depends on: its index, the mode and the size of the pane.
+type PhotoKey = (usize, RenderMode, u16, u16);
+
+impl Tab for GalleryTab {
+    fn title(&self) -> Message {
+        Message::Photos
+    }
+
+    fn transition(&self, _direction: Direction) -> TransitionEffect {
+        TransitionEffect::FadeFromBg
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, view: &TabView) {
+        let inner = photo_pane_inner(area);
+        let key = (self.photo, self.mode, inner.width, inner.height);
+        let mut cache = self.text.borrow_mut();
+        if cache.as_ref().is_none_or(|(drawn, _)| *drawn != key) {
+            let text = PHOTOS.get(self.photo).map_or_else(Text::default, |photo| {
+                photo_text(photo, inner.width, inner.height, self.mode)
+            });
+            *cache = Some((key, text));
+        }
+        let text = cache.as_ref().map(|(_, text)| text).unwrap();
+        render_photo_pane(frame, area, self.photo, self.mode, text, view);
+    }
+
+    fn content_height(&self) -> u16 {
+        DEFAULT_CONTENT_HEIGHT + 8
+    }
+
+    /// The photo is always resized to fit
+    fn max_scroll(&self, _area: Rect, _locale: Locale) -> Vec<u16> {
+        vec![0]
+    }
+
+    fn handle_action(&mut self, action: Action, _cx: &mut TabContext) -> bool {
+        if PHOTOS.is_empty() {
+            return false;
+        }
+        match action {
+            Action::PreviousEntry => {
+                self.photo = (self.photo + PHOTOS.len() - 1) % PHOTOS.len();
+                true
+            }
+            Action::NextEntry => {
+                self.photo = (self.photo + 1) % PHOTOS.len();
+                true
+            }
+            Action::CycleRenderMode => {
+                self.mode = self.mode.next();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn searchable_texts(&self, locale: Locale) -> Vec<Text<'static>> {
+        let caption = PHOTOS
+            .get(self.photo)
+            .map_or("", |photo| photo.caption.get(locale));
+        vec![Text::from(caption)]
+    }
+}
@@ -0,0 +1,154 @@
+use ratzilla::event::KeyEvent;
+use ratzilla::ratatui::layout::Rect;
+use ratzilla::ratatui::text::Text;
+use ratzilla::ratatui::Frame;
+
+use crate::actions::Action;
+use crate::animations::{Direction, TransitionEffect};
+use crate::i18n::{Locale, Message};
+use crate::keymap::Keymap;
+use crate::photos::PHOTOS;
+use crate::search::wrapped_row;
+use crate::ui::{text_max_scroll, Theme};
+
+mod selection;
+pub use selection::*;
+
+mod about;
+pub use about::*;
+
+mod projects;
+pub use projects::*;
+
+mod experiences;
+pub use experiences::*;
+
+mod ferris;
+pub use ferris::*;
+
+mod contact;
+pub use contact::*;
+
+mod posts;
+pub use posts::*;
+
+mod gallery;
+pub use gallery::*;
+
+/// Height of the content area most tabs are laid out for.
+pub const DEFAULT_CONTENT_HEIGHT: u16 = 16;
+
+/// Per-frame state a tab is drawn with.
+pub struct TabView<'a> {
+    pub scroll: &'a [u16], // One offset per pane
+    pub max_scroll: &'a [u16],
+    pub focused_pane: usize,
+    pub selection: &'a ListSelection,
+    pub theme: &'static Theme,
+    pub locale: Locale,
+    pub keymap: &'a Keymap, // For the keys hints name
+}
+
+/// Per-tab state kept in `AppState`, lent to a tab while it handles an action.
+pub struct TabContext<'a> {
+    pub focused_pane: usize,
+    pub scroll: &'a mut [u16],
+    pub selection: &'a mut ListSelection,
+}
+
+/// A tab of the main view, made of one or more focusable panes.
+///
+/// Adding a tab means implementing this trait and listing it in [`registry`].
+pub trait Tab {
+    fn title(&self) -> Message;
+
+    fn render(&self, frame: &mut Frame, area: Rect, view: &TabView);
+
+    /// Effect the content enters and leaves with when the visitor moves `direction`.
+    fn transition(&self, _direction: Direction) -> TransitionEffect {
+        TransitionEffect::default()
+    }
+
+    /// Rows the tab wants for its content area.
+    fn content_height(&self) -> u16 {
+        DEFAULT_CONTENT_HEIGHT
+    }
+
+    fn pane_count(&self) -> usize {
+        1
+    }
+
+    /// Area of every pane when the tab is drawn into `area`.
+    fn pane_areas(&self, area: Rect) -> Vec<Rect> {
+        vec![area]
+    }
+
+    /// How far every pane can scroll when the tab is drawn into `area`.
+    ///
+    /// By default each searchable text is measured against its pane.
+    fn max_scroll(&self, area: Rect, locale: Locale) -> Vec<u16> {
+        self.searchable_texts(locale)
+            .iter()
+            .zip(self.pane_areas(area))
+            .map(|(text, pane_area)| text_max_scroll(text, pane_area))
+            .collect()
+    }
+
+    /// Row a line of a searchable text starts at, as far as its pane scrolls.
+    ///
+    /// `None` when the tab has no such pane, e.g. a hit from before the layout changed.
+    fn line_row(&self, area: Rect, pane: usize, line: usize, locale: Locale) -> Option<u16> {
+        let text = self.searchable_texts(locale).into_iter().nth(pane)?;
+        let pane_area = *self.pane_areas(area).get(pane)?;
+        Some(wrapped_row(&text, line, pane_area.width.saturating_sub(2)))
+    }
+
+    /// Shows a line of a searchable text that scrolling can't reach, e.g. by
+    /// selecting and expanding the list entry it belongs to.
+    fn reveal(&mut self, _pane: usize, _line: usize, _cx: &mut TabContext) {}
+
+    /// Rows a page scroll moves the given pane by.
+    fn page_size(&self, area: Rect, pane: usize) -> u16 {
+        self.pane_areas(area)
+            .get(pane)
+            .map_or(1, |pane_area| pane_area.height.saturating_sub(2))
+    }
+
+    /// Handles a key before the global key bindings, returning `true` if it was consumed.
+    fn handle_key(&mut self, _event: &KeyEvent) -> bool {
+        false
+    }
+
+    /// Handles an action before it runs globally, returning `true` if it was consumed.
+    ///
+    /// Unlike [`Tab::handle_key`] this follows the active key binding preset.
+    fn handle_action(&mut self, _action: Action, _cx: &mut TabContext) -> bool {
+        false
+    }
+
+    /// Opens the entry a `#<tab>/<name>` link names, returning `true` if there is one.
+    fn open(&mut self, _name: &str, _cx: &mut TabContext) -> bool {
+        false
+    }
+
+    /// Text of every searchable pane, one per pane in layout order.
+    fn searchable_texts(&self, _locale: Locale) -> Vec<Text<'static>> {
+        Vec::new()
+    }
+}
+
+/// Every tab in the order they are shown, the gallery only once it has photos.
+pub fn registry() -> Vec<Box<dyn Tab>> {
+    let mut tabs: Vec<Box<dyn Tab>> = vec![
+        Box::new(AboutTab),
+        Box::new(ProjectsTab::default()),
+        Box::new(ExperiencesTab::default()),
+        Box::new(PostsTab::default()),
+    ];
+    if !PHOTOS.is_empty() {
+        tabs.push(Box::new(GalleryTab::default()));
+    }
+    tabs.push(Box::new(FerrisTab));
+    tabs.push(Box::new(ContactTab::new()));
+    tabs
+}
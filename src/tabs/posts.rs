@@ -0,0 +1,102 @@
+use ratzilla::ratatui::layout::Rect;
+use ratzilla::ratatui::text::{Line, Text};
+use ratzilla::ratatui::Frame;
+
+use crate::actions::Action;
+use crate::animations::{Direction, TransitionEffect};
+use crate::i18n::{Locale, Message};
+use crate::posts::{markdown_text, POSTS};
+use crate::tabs::{Tab, TabContext, TabView};
+use crate::ui::{post_items, post_line_row, post_max_scroll, render_list_pane, render_post};
+
+/// Lists the posts and opens one in a reader.
+#[derive(Default)]
+pub struct PostsTab {
+    reading: Option<(usize, Text<'static>)>, // Post in the reader and its body, rendered once
+}
+
+impl Tab for PostsTab {
+    fn title(&self) -> Message {
+        Message::Posts
+    }
+
+    fn transition(&self, _direction: Direction) -> TransitionEffect {
+        TransitionEffect::Sweep
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, view: &TabView) {
+        match &self.reading {
+            Some((post, body)) => render_post(frame, area, &POSTS[*post], body, view),
+            None => render_list_pane(
+                frame,
+                area,
+                view.locale.message(Message::Posts),
+                &view
+                    .keymap
+                    .hint(Message::ReadHint, &[Action::Activate], view.locale),
+                post_items(),
+                false,
+                view,
+            ),
+        }
+    }
+
+    /// The list moves its selection instead of scrolling
+    fn max_scroll(&self, area: Rect, _locale: Locale) -> Vec<u16> {
+        match &self.reading {
+            Some((post, body)) => vec![post_max_scroll(&POSTS[*post], body, area)],
+            None => vec![0],
+        }
+    }
+
+    /// The reader keeps leading whitespace and wraps below the header
+    fn line_row(&self, area: Rect, pane: usize, line: usize, _locale: Locale) -> Option<u16> {
+        match &self.reading {
+            Some((post, body)) if pane == 0 => Some(post_line_row(&POSTS[*post], body, area, line)),
+            Some(_) => None,
+            None => Some(0),
+        }
+    }
+
+    fn handle_action(&mut self, action: Action, cx: &mut TabContext) -> bool {
+        if self.reading.is_some() {
+            if action == Action::Back {
+                self.reading = None;
+                return true;
+            }
+            return false;
+        }
+
+        match action {
+            Action::Activate if !POSTS.is_empty() => {
+                let post = cx.selection.selected;
+                self.reading = Some((post, markdown_text(POSTS[post].body)));
+                cx.scroll[0] = 0;
+                true
+            }
+            _ => cx.selection.navigate(action, POSTS.len()),
+        }
+    }
+
+    fn open(&mut self, name: &str, cx: &mut TabContext) -> bool {
+        let Some(post) = POSTS.iter().position(|post| post.slug == name) else {
+            return false;
+        };
+        cx.selection.selected = post;
+        self.reading = Some((post, markdown_text(POSTS[post].body)));
+        cx.scroll[0] = 0;
+        true
+    }
+
+    fn searchable_texts(&self, _locale: Locale) -> Vec<Text<'static>> {
+        match &self.reading {
+            Some((_, body)) => vec![body.clone()],
+            None => vec![Text::from(
+                POSTS
+                    .iter()
+                    .map(|post| Line::from(post.title))
+                    .collect::<Vec<_>>(),
+            )],
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use ratzilla::ratatui::layout::{Constraint, Rect};
+use ratzilla::ratatui::text::Text;
+use ratzilla::ratatui::widgets::ScrollbarOrientation;
+use ratzilla::ratatui::Frame;
+
+use crate::actions::Action;
+use crate::animations::{Direction, TransitionEffect};
+use crate::data::PROJECTS;
+use crate::i18n::{Locale, Message};
+use crate::tabs::{Tab, TabContext, TabView, DEFAULT_CONTENT_HEIGHT};
+use crate::ui::{
+    contributions_text, list_text_width, project_detail_areas, project_detail_text, project_items,
+    projects_areas, projects_text, render_contribution_heatmap, render_list_pane,
+    render_project_detail, render_scrollbar, render_text_pane, text_max_scroll, text_pane_areas,
+    TextPane,
+};
+
+const PANES: &[TextPane] = &[
+    TextPane {
+        title: Message::Projects,
+        text: projects_text,
+        constraint: Constraint::Fill(1),
+    },
+    TextPane {
+        title: Message::Contributions,
+        text: contributions_text,
+        constraint: Constraint::Length(4),
+    },
+];
+
+/// Projects are a list, Enter opens the detail view of the selected one.
+#[derive(Default)]
+pub struct ProjectsTab {
+    detail: Option<usize>, // Project shown in the detail view, the list otherwise
+}
+
+impl Tab for ProjectsTab {
+    fn title(&self) -> Message {
+        Message::Projects
+    }
+
+    fn transition(&self, _direction: Direction) -> TransitionEffect {
+        TransitionEffect::Slide
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, view: &TabView) {
+        if let Some(project) = self.detail {
+            render_project_detail(frame, area, &PROJECTS[project], view);
+            return;
+        }
+
+        let [list_area, contributions_area] = self.pane_areas(area)[..] else {
+            return;
+        };
+        render_contribution_heatmap(frame, projects_areas(area)[1], view.theme, view.locale);
+        render_list_pane(
+            frame,
+            list_area,
+            view.locale.message(PANES[0].title),
+            &view
+                .keymap
+                .hint(Message::DetailsHint, &[Action::Activate], view.locale),
+            project_items(list_text_width(list_area), view.locale),
+            view.focused_pane == 0,
+            view,
+        );
+        render_text_pane(
+            frame,
+            contributions_area,
+            view.locale.message(PANES[1].title),
+            (PANES[1].text)(view.locale),
+            view.scroll[1],
+            view.focused_pane == 1,
+            view.theme,
+        );
+        render_scrollbar(
+            frame,
+            contributions_area,
+            view.scroll[1],
+            view.max_scroll[1],
+            ScrollbarOrientation::VerticalRight,
+        );
+    }
+
+    /// Room for the contribution heatmap under the panes
+    fn content_height(&self) -> u16 {
+        DEFAULT_CONTENT_HEIGHT + 6
+    }
+
+    fn pane_count(&self) -> usize {
+        if self.detail.is_some() {
+            1
+        } else {
+            PANES.len()
+        }
+    }
+
+    fn pane_areas(&self, area: Rect) -> Vec<Rect> {
+        if let Some(project) = self.detail {
+            // Only the write-up scrolls, the links below it stay put
+            vec![project_detail_areas(area, &PROJECTS[project])[0]]
+        } else {
+            text_pane_areas(projects_areas(area)[0], PANES)
+        }
+    }
+
+    /// The list moves its selection instead of scrolling
+    fn max_scroll(&self, area: Rect, locale: Locale) -> Vec<u16> {
+        let areas = self.pane_areas(area);
+        let texts = self.searchable_texts(locale);
+        if self.detail.is_some() {
+            vec![text_max_scroll(&texts[0], areas[0])]
+        } else {
+            vec![0, text_max_scroll(&texts[1], areas[1])]
+        }
+    }
+
+    fn handle_action(&mut self, action: Action, cx: &mut TabContext) -> bool {
+        if self.detail.is_some() {
+            if action == Action::Back {
+                self.detail = None;
+                return true;
+            }
+            return false;
+        }
+        if cx.focused_pane != 0 {
+            return false;
+        }
+
+        match action {
+            Action::Activate => {
+                self.detail = Some(cx.selection.selected);
+                cx.scroll[0] = 0;
+                true
+            }
+            _ => cx.selection.navigate(action, PROJECTS.len()),
+        }
+    }
+
+    /// Every project is a line of the list's text
+    fn reveal(&mut self, pane: usize, line: usize, cx: &mut TabContext) {
+        if self.detail.is_none() && pane == 0 {
+            cx.selection.selected = line;
+        }
+    }
+
+    /// Projects are named in lower case, as in `#projects/emiv`
+    fn open(&mut self, name: &str, cx: &mut TabContext) -> bool {
+        let Some(project) = PROJECTS
+            .iter()
+            .position(|project| project.name.to_lowercase() == name)
+        else {
+            return false;
+        };
+        cx.selection.selected = project;
+        self.detail = Some(project);
+        cx.scroll[0] = 0;
+        true
+    }
+
+    fn searchable_texts(&self, locale: Locale) -> Vec<Text<'static>> {
+        match self.detail {
+            Some(project) => vec![project_detail_text(&PROJECTS[project], locale)],
+            None => PANES.iter().map(|pane| (pane.text)(locale)).collect(),
+        }
+    }
+}
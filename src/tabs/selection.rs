@@ -0,0 +1,44 @@
+use crate::actions::Action;
+
+/// Cursor and expanded entries of a tab's selectable list.
+#[derive(Clone, Debug, Default)]
+pub struct ListSelection {
+    pub selected: usize,
+    expanded: Vec<usize>,
+}
+
+impl ListSelection {
+    pub fn is_expanded(&self, entry: usize) -> bool {
+        self.expanded.contains(&entry)
+    }
+
+    pub fn expand(&mut self, entry: usize) {
+        if !self.is_expanded(entry) {
+            self.expanded.push(entry);
+        }
+    }
+
+    pub fn toggle_expanded(&mut self) {
+        match self.expanded.iter().position(|e| *e == self.selected) {
+            Some(i) => {
+                self.expanded.remove(i);
+            }
+            None => self.expanded.push(self.selected),
+        }
+    }
+
+    /// Moves the cursor over `len` entries for the scrolling actions.
+    ///
+    /// Returns `false` for any other action.
+    pub fn navigate(&mut self, action: Action, len: usize) -> bool {
+        let last = len.saturating_sub(1);
+        match action {
+            Action::ScrollDown => self.selected = (self.selected + 1).min(last),
+            Action::ScrollUp => self.selected = self.selected.saturating_sub(1),
+            Action::ScrollToTop => self.selected = 0,
+            Action::ScrollToBottom => self.selected = last,
+            _ => return false,
+        }
+        true
+    }
+}
@@ -0,0 +1,68 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Widget};
+
+use crate::art::AsciiArt;
+use crate::ui::{block_width, slice_columns};
+
+/// The variant of an [`AsciiArt`] that fits, centered horizontally.
+///
+/// When even the smallest variant is too wide it is cropped, `scroll` moving
+/// the visible columns across it.
+pub struct ArtView<'a> {
+    art: &'a AsciiArt,
+    scroll: u16,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> ArtView<'a> {
+    pub fn new(art: &'a AsciiArt) -> Self {
+        Self {
+            art,
+            scroll: 0,
+            block: None,
+        }
+    }
+
+    pub fn scroll(mut self, scroll: u16) -> Self {
+        self.scroll = scroll;
+        self
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+/// Columns the art drawn into `area` is cropped by, how far it can scroll sideways.
+pub fn art_max_scroll(art: &AsciiArt, area: Rect) -> u16 {
+    block_width(art.fit(area.width, area.height)).saturating_sub(area.width)
+}
+
+impl Widget for ArtView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = match &self.block {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.clone().render(area, buf);
+                inner
+            }
+            None => area,
+        };
+
+        let variant = self.art.fit(area.width, area.height);
+        let width = block_width(variant);
+        let x = area.x + area.width.saturating_sub(width) / 2;
+        let first_column = self.scroll.min(art_max_scroll(self.art, area));
+        for (row, line) in variant.lines().take(area.height as usize).enumerate() {
+            buf.set_string(
+                x,
+                area.y + row as u16,
+                slice_columns(line, first_column, area.width),
+                Style::default(),
+            );
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::widgets::Widget;
+
+use crate::ui::PAGE_COLOR;
+
+/// Characters the rain is made of.
+const RAIN_GLYPHS: &[char] = &[
+    '0', '1', '2', '3', '4', '5', '7', '8', '9', 'Z', 'T', 'H', 'E', 'M', 'A', 'R', 'I', 'X', ':',
+    '.', '"', '=', '*', '+', '-', '<', '>', '|', '¦', '╌',
+];
+
+/// Columns of characters falling down the screen, each at its own speed.
+#[derive(Default)]
+pub struct MatrixRain {
+    area: Rect,
+    drops: Vec<Drop>,  // One for each column
+    glyphs: Vec<char>, // One for each cell, row by row
+}
+
+struct Drop {
+    head: f32,  // Row of the brightest character, above the screen before it enters
+    speed: f32, // Rows per frame
+    length: f32,
+}
+
+impl Drop {
+    fn new(height: u16) -> Self {
+        Self {
+            head: -(fastrand::f32() * height as f32),
+            speed: 0.2 + fastrand::f32() * 0.6,
+            length: 4.0 + fastrand::f32() * (height as f32 / 2.0),
+        }
+    }
+}
+
+impl MatrixRain {
+    /// Moves the rain one frame on, starting over when `area` changed size.
+    pub fn step(&mut self, area: Rect) {
+        if area != self.area {
+            self.area = area;
+            self.drops = (0..area.width).map(|_| Drop::new(area.height)).collect();
+            self.glyphs = (0..area.area()).map(|_| random_glyph()).collect();
+        }
+        for drop in &mut self.drops {
+            drop.head += drop.speed;
+            if drop.head - drop.length > self.area.height as f32 {
+                *drop = Drop::new(self.area.height);
+            }
+        }
+        // A few characters change on every frame
+        for _ in 0..self.glyphs.len() / 50 {
+            let cell = fastrand::usize(..self.glyphs.len());
+            self.glyphs[cell] = random_glyph();
+        }
+    }
+}
+
+fn random_glyph() -> char {
+    RAIN_GLYPHS[fastrand::usize(..RAIN_GLYPHS.len())]
+}
+
+impl Widget for &MatrixRain {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(self.area);
+        for (x, drop) in (area.left()..area.right()).zip(&self.drops) {
+            for y in area.top()..area.bottom() {
+                let distance = drop.head - (y - self.area.y) as f32;
+                if distance < 0.0 || distance > drop.length {
+                    continue;
+                }
+                let index = (y - self.area.y) as usize * self.area.width as usize
+                    + (x - self.area.x) as usize;
+                let color = if distance < 1.0 {
+                    Color::Rgb(200, 255, 200)
+                } else {
+                    blend(Color::Rgb(30, 200, 70), 1.0 - distance / drop.length)
+                };
+                buf[(x, y)].set_char(self.glyphs[index]).set_fg(color);
+            }
+        }
+    }
+}
+
+/// Stars flying towards the visitor from the middle of the screen.
+#[derive(Default)]
+pub struct Starfield {
+    area: Rect,
+    stars: Vec<Star>,
+}
+
+struct Star {
+    x: f32, // -1 to 1 across the screen at the farthest
+    y: f32,
+    z: f32, // Distance, from 1 down to the screen at 0
+}
+
+impl Star {
+    fn new(z: f32) -> Self {
+        Self {
+            x: fastrand::f32() * 2.0 - 1.0,
+            y: fastrand::f32() * 2.0 - 1.0,
+            z,
+        }
+    }
+
+    /// Column and row of `area` the star shows at, `None` once it left it.
+    fn position(&self, area: Rect) -> Option<(u16, u16)> {
+        let (half_width, half_height) = (area.width as f32 / 2.0, area.height as f32 / 2.0);
+        let x = half_width + self.x / self.z * half_width;
+        let y = half_height + self.y / self.z * half_height;
+        let inside =
+            (0.0..area.width as f32).contains(&x) && (0.0..area.height as f32).contains(&y);
+        inside.then_some((area.x + x as u16, area.y + y as u16))
+    }
+}
+
+impl Starfield {
+    /// Moves the stars one frame on, starting over when `area` changed size.
+    pub fn step(&mut self, area: Rect) {
+        if area != self.area {
+            self.area = area;
+            // Spread over every distance so the screen isn't empty at first
+            self.stars = (0..area.area() / 30)
+                .map(|_| Star::new(0.05 + fastrand::f32() * 0.95))
+                .collect();
+        }
+        for star in &mut self.stars {
+            star.z -= 0.01;
+            if star.z < 0.05 || star.position(self.area).is_none() {
+                *star = Star::new(1.0);
+            }
+        }
+    }
+}
+
+impl Widget for &Starfield {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        for star in &self.stars {
+            let Some(position) = star.position(self.area) else {
+                continue;
+            };
+            if !area.contains(position.into()) {
+                continue;
+            }
+            // Nearer stars are bigger and brighter
+            let symbol = match star.z {
+                z if z > 0.6 => '.',
+                z if z > 0.3 => '+',
+                _ => '*',
+            };
+            buf[position]
+                .set_char(symbol)
+                .set_fg(blend(Color::Rgb(230, 230, 255), 1.0 - star.z));
+        }
+    }
+}
+
+/// `color` faded into the page by `1 - amount`.
+fn blend(color: Color, amount: f32) -> Color {
+    let (Color::Rgb(r, g, b), Color::Rgb(page_r, page_g, page_b)) = (color, PAGE_COLOR) else {
+        return color;
+    };
+    let mix = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * amount) as u8;
+    Color::Rgb(mix(page_r, r), mix(page_g, g), mix(page_b, b))
+}
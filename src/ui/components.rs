@@ -1,78 +1,165 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::actions::Action;
+use crate::backend::Hyperlink;
 use ratatui::prelude::Span;
-use ratzilla::ratatui::layout::{Offset, Rect};
+use ratzilla::ratatui::layout::{Constraint, Layout, Margin, Rect};
 use ratzilla::ratatui::style::{Style, Stylize};
 use ratzilla::ratatui::text::{Line, Text};
 use ratzilla::ratatui::widgets::Wrap;
 use ratzilla::ratatui::Frame;
 use ratzilla::ratatui::{
     layout::Alignment,
-    widgets::{Block, Paragraph},
+    widgets::{Block, Clear, ListItem, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
 };
-use ratzilla::widgets::Hyperlink;
 
 use crate::data::*;
+use crate::date::{DateRange, Month};
+use crate::figlet::{Font, FONTS};
+use crate::i18n::{Locale, Message};
+use crate::keymap::Keymap;
+use crate::palette::Palette;
+use crate::posts::POSTS;
+use crate::stats::{crate_stats, repository_stats, thousands, STATS_AS_OF};
+use crate::tabs::ListSelection;
+use crate::ui::{display_width, pad_to_width, ArtView, Theme, TimelineEntry};
 
-pub fn render_whoami(frame: &mut Frame<'_>, description_area: Rect, scroll: u16) {
-    frame.render_widget(
-        Paragraph::new(DESCRIPTION)
-            .wrap(Wrap { trim: true })
-            .left_aligned()
-            .scroll((scroll, 0)) // Add scrolling using the scroll position
-            .block(Block::bordered().title("whoami".bold().underlined())),
-        description_area,
-    );
+pub fn whoami_text(locale: Locale) -> Text<'static> {
+    Text::from(DESCRIPTION.get(locale))
 }
 
-pub fn render_education(frame: &mut Frame<'_>, education_area: Rect) {
+pub fn education_text(locale: Locale) -> Text<'static> {
     let mut lines = Vec::new();
 
-    for (university, field, gpa) in EDUCATION.iter() {
+    for (university, field, gpa, _) in EDUCATION.iter() {
         let mut line = Line::default();
         line.spans
             .push(Span::styled(*university, Style::default().bold()));
         line.spans.push(Span::raw(", "));
-        line.spans.push(Span::raw(*field));
+        line.spans.push(Span::raw(field.get(locale)));
         line.spans.push(Span::raw(", "));
         line.spans.push(Span::raw(*gpa));
         lines.push(line);
     }
 
-    let text = Text::from(lines);
-
-    frame.render_widget(
-        Paragraph::new(text)
-            .wrap(Wrap { trim: true })
-            .left_aligned()
-            .block(Block::bordered().title("Education".bold().underlined())),
-        education_area,
-    );
+    Text::from(lines)
 }
 
-pub fn render_projects(frame: &mut Frame<'_>, projects_area: Rect, scroll: u16) {
+pub fn projects_text(locale: Locale) -> Text<'static> {
     let mut lines = Vec::new();
 
-    for (name, description) in PROJECTS.iter() {
+    for project in PROJECTS.iter() {
         let mut line = Line::default();
         line.spans
-            .push(Span::styled(*name, Style::default().bold()));
+            .push(Span::styled(project.name, Style::default().bold()));
         line.spans.push(Span::raw(": "));
-        line.spans.push(Span::raw(*description));
+        line.spans.push(Span::raw(project.description));
+        if let Some(stats) = stats_summary(project, locale) {
+            line.spans.push(Span::raw(format!(" ({})", stats)));
+        }
         lines.push(line);
     }
 
-    let text = Text::from(lines);
+    Text::from(lines)
+}
 
-    frame.render_widget(
-        Paragraph::new(text)
-            .wrap(Wrap { trim: true })
-            .left_aligned()
-            .scroll((scroll, 0))
-            .block(Block::bordered().title("Projects".bold().underlined())),
-        projects_area,
-    );
+/// Bold `Label: ` that starts a line of project details.
+fn detail_label(message: Message, locale: Locale) -> Span<'static> {
+    Span::styled(
+        format!("{}: ", locale.message(message)),
+        Style::default().bold(),
+    )
+}
+
+pub fn status_label(status: ProjectStatus, locale: Locale) -> &'static str {
+    locale.message(match status {
+        ProjectStatus::Active => Message::Active,
+        ProjectStatus::Maintained => Message::Maintained,
+        ProjectStatus::Finished => Message::Finished,
+    })
+}
+
+pub fn project_detail_text(project: &Project, locale: Locale) -> Text<'static> {
+    let mut lines = vec![Line::from(project.description), Line::default()];
+
+    if let Some(project_status) = project.status {
+        let mut status = Line::default();
+        status.spans.push(detail_label(Message::Status, locale));
+        status
+            .spans
+            .push(Span::raw(status_label(project_status, locale)));
+        lines.push(status);
+    }
+
+    let mut tags = Line::default();
+    tags.spans.push(detail_label(Message::Tech, locale));
+    tags.spans.push(Span::raw(project.tags.join(", ")));
+    lines.push(tags);
+
+    if let Some(crate_name) = project.crate_name {
+        let mut published = Line::default();
+        published.spans.push(detail_label(Message::Crate, locale));
+        published.spans.push(Span::raw(crate_name));
+        if let Some(stats) = crate_stats(project) {
+            published.spans.push(Span::raw(format!(
+                " v{}, {}",
+                stats.version,
+                locale.format(Message::Downloads, thousands(stats.downloads))
+            )));
+        }
+        lines.push(published);
+    }
+
+    if let Some(stats) = repository_stats(project) {
+        let mut stars = Line::default();
+        stars.spans.push(detail_label(Message::Stars, locale));
+        stars.spans.push(Span::raw(thousands(stats.stars)));
+        lines.push(stars);
+    }
+
+    if let Some(write_up) = project.write_up {
+        lines.push(Line::default());
+        lines.push(Line::from(write_up));
+    }
+
+    if let Some(as_of) = STATS_AS_OF {
+        if crate_stats(project).is_some() || repository_stats(project).is_some() {
+            lines.push(Line::default());
+            lines.push(Line::from(locale.format(Message::StatsAsOf, as_of)).italic());
+        }
+    }
+
+    Text::from(lines)
+}
+
+/// Projects as list entries, with their status and stats.
+pub fn project_items(width: u16, locale: Locale) -> Vec<ListItem<'static>> {
+    PROJECTS
+        .iter()
+        .map(|project| {
+            let mut title = Line::from(Span::styled(project.name, Style::default().bold()));
+            if let Some(status) = project.status {
+                title
+                    .spans
+                    .push(Span::raw(format!(" [{}]", status_label(status, locale))).italic());
+            }
+            let mut lines = vec![title];
+            lines.extend(wrap_lines(project.description, width, ""));
+            if let Some(stats) = stats_summary(project, locale) {
+                lines.extend(
+                    wrap_lines(&stats, width, "")
+                        .into_iter()
+                        .map(|line| line.italic()),
+                );
+            }
+            ListItem::new(lines)
+        })
+        .collect()
 }
 
-pub fn render_contributions(frame: &mut Frame<'_>, contributions_area: Rect) {
+pub fn contributions_text(_locale: Locale) -> Text<'static> {
     let mut lines = Vec::new();
 
     for (project, description, commits) in CONTRIBUTIONS.iter() {
@@ -86,47 +173,168 @@ pub fn render_contributions(frame: &mut Frame<'_>, contributions_area: Rect) {
         lines.push(line);
     }
 
-    let text = Text::from(lines);
+    Text::from(lines)
+}
 
-    frame.render_widget(
-        Paragraph::new(text)
-            .wrap(Wrap { trim: true })
-            .left_aligned()
-            .block(Block::bordered().title("Contributions".bold().underlined())),
-        contributions_area,
+/// A duration of `data.rs`, which the tests of `date.rs` all parse, so debug
+/// builds stop at one that was edited into something else.
+fn parse_duration(duration: &str) -> Option<DateRange> {
+    let range = DateRange::parse(duration);
+    debug_assert!(
+        range.is_ok(),
+        "invalid duration {:?}: {:?}",
+        duration,
+        range
     );
+    range.ok()
+}
+
+/// A duration as `March 2025 – Present · 7 mos`, kept as written when it does not parse.
+pub fn duration_label(duration: &str, locale: Locale) -> String {
+    match parse_duration(duration) {
+        Some(range) => format!(
+            "{} · {}",
+            range.label(locale),
+            range.duration(Month::current(), locale)
+        ),
+        None => duration.to_string(),
+    }
 }
 
-pub fn render_experiences(frame: &mut Frame<'_>, projects_area: Rect, scroll: u16) {
+/// Experiences newest first, the order every view lists them in.
+pub fn experiences_newest_first() -> Vec<&'static Experience> {
+    let mut experiences: Vec<_> = EXPERIENCES.iter().collect();
+    experiences.sort_by(|a, b| {
+        match (parse_duration(a.2), parse_duration(b.2)) {
+            (Some(a), Some(b)) => a.newest_first(&b),
+            // Durations that do not parse go last, in their written order
+            (a, b) => a.is_none().cmp(&b.is_none()),
+        }
+    });
+    experiences
+}
+
+pub fn experiences_text(locale: Locale) -> Text<'static> {
     let mut lines = Vec::new();
 
-    for (company, title, duration, bullet_1, bullet_2) in EXPERIENCES.iter() {
+    for (company, title, duration, bullet_1, bullet_2) in experiences_newest_first() {
         let mut line = Line::default();
         line.spans
             .push(Span::styled(*company, Style::default().bold()));
         line.spans.push(Span::raw(", "));
-        line.spans.push(Span::raw(*title));
+        line.spans.push(Span::raw(title.get(locale)));
         line.spans.push(Span::raw(", "));
-        line.spans.push(Span::raw(*duration));
+        line.spans.push(Span::raw(duration_label(duration, locale)));
         lines.push(line);
 
-        lines.push(Line::from(format!("• {}", bullet_1)));
-        lines.push(Line::from(format!("• {}", bullet_2)));
+        lines.push(Line::from(format!("• {}", bullet_1.get(locale))));
+        lines.push(Line::from(format!("• {}", bullet_2.get(locale))));
         lines.push(Line::default());
     }
-    let text = Text::from(lines);
+    Text::from(lines)
+}
 
-    frame.render_widget(
-        Paragraph::new(text)
-            .wrap(Wrap { trim: true })
-            .left_aligned()
-            .scroll((scroll, 0))
-            .block(Block::bordered().title("Experiences".bold().underlined())),
-        projects_area,
-    );
+/// Experiences as list entries, expanded ones showing what was done there.
+pub fn experience_items(
+    selection: &ListSelection,
+    width: u16,
+    locale: Locale,
+) -> Vec<ListItem<'static>> {
+    experiences_newest_first()
+        .into_iter()
+        .enumerate()
+        .map(|(i, (company, title, duration, bullet_1, bullet_2))| {
+            let mut lines = vec![Line::from(Span::styled(*company, Style::default().bold()))];
+            lines.extend(wrap_lines(
+                &format!(
+                    "{}, {}",
+                    title.get(locale),
+                    duration_label(duration, locale)
+                ),
+                width,
+                "",
+            ));
+            if selection.is_expanded(i) {
+                for bullet in [bullet_1, bullet_2] {
+                    lines.extend(wrap_lines(
+                        &format!("• {}", bullet.get(locale)),
+                        width,
+                        "  ",
+                    ));
+                }
+            }
+            ListItem::new(lines)
+        })
+        .collect()
 }
 
-pub fn render_publications(frame: &mut Frame<'_>, publications_area: Rect) {
+/// Fills the timeline bar of a role.
+pub const ROLE_SYMBOL: &str = "█";
+/// Fills the timeline bar of a study.
+pub const STUDY_SYMBOL: &str = "░";
+
+/// Roles and studies as timeline bars, roles solid and studies shaded, studies
+/// without dates left out.
+pub fn timeline_entries(locale: Locale) -> Vec<TimelineEntry> {
+    let roles = experiences_newest_first()
+        .into_iter()
+        .map(|(_, title, duration, _, _)| (title.get(locale), *duration, ROLE_SYMBOL));
+    let studies = EDUCATION.iter().filter_map(|(_, field, _, duration)| {
+        Some((field.get(locale), (*duration)?, STUDY_SYMBOL))
+    });
+    roles
+        .chain(studies)
+        .filter_map(|(label, duration, symbol)| {
+            Some(TimelineEntry {
+                label: label.to_string(),
+                range: parse_duration(duration)?,
+                symbol,
+            })
+        })
+        .collect()
+}
+
+pub fn post_items() -> Vec<ListItem<'static>> {
+    POSTS
+        .iter()
+        .map(|post| {
+            let mut line = Line::default();
+            line.spans
+                .push(Span::styled(post.date, Style::default().italic()));
+            line.spans.push(Span::raw("  "));
+            line.spans
+                .push(Span::styled(post.title, Style::default().bold()));
+            if !post.tags.is_empty() {
+                line.spans
+                    .push(Span::raw(format!("  #{}", post.tags.join(" #"))));
+            }
+            ListItem::new(line)
+        })
+        .collect()
+}
+
+/// Download and star counts of a project, when the stats snapshot has them.
+pub fn stats_summary(project: &Project, locale: Locale) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(stats) = crate_stats(project) {
+        parts.push(locale.format(Message::Downloads, thousands(stats.downloads)));
+    }
+    if let Some(stats) = repository_stats(project) {
+        parts.push(format!("★ {}", thousands(stats.stars)));
+    }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Wraps `text` to `width` columns, continuation lines indented by `indent`.
+fn wrap_lines(text: &str, width: u16, indent: &str) -> Vec<Line<'static>> {
+    let options = textwrap::Options::new(width.max(1) as usize).subsequent_indent(indent);
+    textwrap::wrap(text, options)
+        .into_iter()
+        .map(|line| Line::from(line.into_owned()))
+        .collect()
+}
+
+pub fn publications_text(_locale: Locale) -> Text<'static> {
     let mut lines = Vec::new();
 
     for (paper, info) in PUBLICATIONS.iter() {
@@ -139,37 +347,324 @@ pub fn render_publications(frame: &mut Frame<'_>, publications_area: Rect) {
         lines.push(conference_line);
     }
 
-    let text = Text::from(lines);
+    Text::from(lines)
+}
+
+/// Bordered pane of wrapped text, highlighted while it has the focus.
+pub fn render_text_pane(
+    frame: &mut Frame<'_>,
+    pane_area: Rect,
+    title: &str,
+    text: Text<'static>,
+    scroll: u16,
+    focused: bool,
+    theme: &Theme,
+) {
+    let mut block = Block::bordered().title(title.bold().underlined());
+    if focused {
+        block = block.border_style(Style::default().fg(theme.highlight));
+    }
 
     frame.render_widget(
         Paragraph::new(text)
             .wrap(Wrap { trim: true })
             .left_aligned()
-            .block(Block::bordered().title("Publications".bold().underlined())),
-        publications_area,
+            .scroll((scroll, 0))
+            .block(block),
+        pane_area,
     );
 }
 
+/// Draws a scrollbar over the border of a pane that has something to scroll.
+pub fn render_scrollbar(
+    frame: &mut Frame<'_>,
+    pane_area: Rect,
+    scroll: u16,
+    max_scroll: u16,
+    orientation: ScrollbarOrientation,
+) {
+    if max_scroll == 0 {
+        return;
+    }
+    let margin = match orientation {
+        ScrollbarOrientation::VerticalRight | ScrollbarOrientation::VerticalLeft => Margin {
+            horizontal: 0,
+            vertical: 1,
+        },
+        _ => Margin {
+            horizontal: 1,
+            vertical: 0,
+        },
+    };
+    let mut state = ScrollbarState::new(max_scroll as usize).position(scroll as usize);
+    frame.render_stateful_widget(
+        Scrollbar::new(orientation)
+            .begin_symbol(None)
+            .end_symbol(None),
+        pane_area.inner(margin),
+        &mut state,
+    );
+}
+
+static PARSED_FONTS: OnceLock<Vec<Font>> = OnceLock::new();
+
+thread_local! {
+    /// Headers drawn at the last width, by text, as a post asks for its own every frame.
+    static HEADERS: RefCell<(u16, HashMap<String, Vec<String>>)> = RefCell::default();
+}
+
+/// `text` drawn with the most detailed bundled font that fits in `width` columns,
+/// nothing when only the one-row font would.
+pub fn figlet_header(text: &str, width: u16) -> Vec<String> {
+    HEADERS.with_borrow_mut(|(cached_width, headers)| {
+        if *cached_width != width {
+            *cached_width = width;
+            headers.clear();
+        }
+        headers
+            .entry(text.to_string())
+            .or_insert_with(|| {
+                let fonts = PARSED_FONTS.get_or_init(|| {
+                    FONTS
+                        .iter()
+                        .filter_map(|(_, source)| Font::parse(source).ok())
+                        .collect()
+                });
+                fonts
+                    .iter()
+                    .map(|font| font.render(text))
+                    .find(|rows| {
+                        rows.len() > 1 && rows.iter().all(|row| display_width(row) <= width)
+                    })
+                    .unwrap_or_default()
+            })
+            .clone()
+    })
+}
+
 pub fn render_banner(frame: &mut Frame<'_>, banner_area: Rect) {
+    frame.render_widget(ArtView::new(&BANNER), banner_area);
+}
+
+pub fn render_links(frame: &mut Frame<'_>, links_area: Rect, locale: Locale) {
     frame.render_widget(
-        Paragraph::new(BANNER).alignment(Alignment::Center),
-        banner_area,
+        Block::bordered().title(locale.message(Message::Links).bold().underlined()),
+        links_area,
     );
+    for ((_, url), area) in LINKS.iter().zip(link_areas(links_area)) {
+        frame.render_widget(Hyperlink::new(*url), area);
+    }
+}
+
+/// Where `render_links` draws each of `LINKS`, one per row.
+///
+/// A link is never cut short, as what it shows is also where it leads.
+pub fn link_areas(links_area: Rect) -> Vec<Rect> {
+    LINKS
+        .iter()
+        .enumerate()
+        .map(|(i, (_, url))| {
+            let y = links_area.y + i as u16 + 1;
+            Rect::new(links_area.x + 1, y, display_width(url), 1)
+        })
+        .collect()
+}
+
+/// Where the tab bar drawn over `tabs_area` puts each of `titles`, leaving out
+/// the padding and dividers between them.
+pub fn tab_title_areas(tabs_area: Rect, titles: &[&str]) -> Vec<Rect> {
+    let inner = tabs_area.inner(Margin::new(1, 1));
+    let mut x = inner.x;
+    titles
+        .iter()
+        .map(|title| {
+            let width = display_width(title);
+            let area = Rect::new(x + 1, inner.y, width, 1).intersection(inner);
+            x += width + 3; // Padding on both sides and the divider
+            area
+        })
+        .collect()
 }
 
-pub fn render_links(frame: &mut Frame<'_>, links_area: Rect) {
+pub fn contact_card_text() -> Text<'static> {
+    let mut lines = vec![
+        Line::from(Span::styled(CONTACT.full_name(), Style::default().bold())),
+        Line::from(CONTACT.email),
+    ];
+    if let Some(phone) = CONTACT.phone {
+        lines.push(Line::from(phone));
+    }
+    lines.push(Line::default());
+    for (name, url) in CONTACT.socials.iter() {
+        let mut line = Line::default();
+        line.spans
+            .push(Span::styled(*name, Style::default().bold()));
+        line.spans.push(Span::raw(": "));
+        line.spans.push(Span::raw(*url));
+        lines.push(line);
+    }
+
+    Text::from(lines)
+}
+
+pub fn render_contact_card(frame: &mut Frame<'_>, card_area: Rect, scroll: u16, locale: Locale) {
+    let text = contact_card_text();
+
     frame.render_widget(
-        Block::bordered().title("Links".bold().underlined()),
-        links_area,
+        Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .left_aligned()
+            .scroll((scroll, 0))
+            .block(Block::bordered().title(locale.message(Message::Contact).bold().underlined())),
+        card_area,
+    );
+}
+
+/// The QR code with `hint` under it.
+pub fn render_contact_qr(frame: &mut Frame<'_>, qr_area: Rect, qr: &[Line<'static>], hint: &str) {
+    let [code_area, hint_area] =
+        Layout::vertical([Constraint::Length(qr.len() as u16), Constraint::Length(1)])
+            .areas(qr_area);
+    frame.render_widget(
+        Paragraph::new(Text::from(qr.to_vec())).alignment(Alignment::Center),
+        code_area,
+    );
+    frame.render_widget(Paragraph::new(hint).italic().centered(), hint_area);
+}
+
+pub fn render_command_palette(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    palette: &Palette,
+    actions: &[Action],
+    tab_titles: &[&str],
+    theme: &Theme,
+    locale: Locale,
+) {
+    let matches = palette.matches(actions, tab_titles, locale);
+    let height = (matches.len() as u16 + 3).min(area.height);
+    let width = 44.min(area.width);
+    let palette_area = centered_rect(area, width, height);
+
+    // Keep the selected entry inside the visible part of the list
+    let visible = height.saturating_sub(3) as usize;
+    let offset = (palette.selected + 1).saturating_sub(visible);
+
+    let mut lines = vec![Line::from(format!("> {}▏", palette.query)).bold()];
+    for (i, action) in matches.iter().enumerate().skip(offset) {
+        let line = Line::from(format!("  {}", action.label(tab_titles, locale)));
+        if i == palette.selected {
+            lines.push(line.style(Style::default().fg(theme.background).bg(theme.highlight)));
+        } else {
+            lines.push(line);
+        }
+    }
+
+    frame.render_widget(Clear, palette_area);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).block(
+            Block::bordered()
+                .title(locale.message(Message::Commands).bold().underlined())
+                .style(Style::default().fg(theme.foreground).bg(theme.background)),
+        ),
+        palette_area,
+    );
+}
+
+pub fn render_help(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    keymap: &Keymap,
+    tab_titles: &[&str],
+    theme: &Theme,
+    locale: Locale,
+) {
+    let entries = keymap.help_entries();
+    let key_width = entries
+        .iter()
+        .map(|(keys, _)| display_width(keys))
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = vec![
+        Line::from(locale.format(Message::Preset, keymap.name)).italic(),
+        Line::default(),
+    ];
+    for (keys, action) in entries.iter() {
+        let mut line = Line::default();
+        line.spans.push(Span::styled(
+            pad_to_width(keys, key_width, Alignment::Right),
+            Style::default().bold(),
+        ));
+        line.spans.push(Span::raw("  "));
+        line.spans.push(Span::raw(action.label(tab_titles, locale)));
+        lines.push(line);
+    }
+
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let width = 52.min(area.width);
+    let help_area = centered_rect(area, width, height);
+
+    frame.render_widget(Clear, help_area);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).block(
+            Block::bordered()
+                .title(locale.message(Message::KeyBindings).bold().underlined())
+                .style(Style::default().fg(theme.foreground).bg(theme.background)),
+        ),
+        help_area,
     );
-    for (i, (_, url)) in LINKS.iter().enumerate() {
-        let link = Hyperlink::new(*url);
-        frame.render_widget(
-            link,
-            links_area.offset(Offset {
-                x: 1,
-                y: i as i32 + 1,
-            }),
-        );
+}
+
+/// Rect of the given size centered inside `area`, used for overlays.
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Rows `text` takes up once wrapped the way the panes wrap it.
+pub fn wrapped_height(text: &Text<'_>, width: u16) -> u16 {
+    Paragraph::new(text.clone())
+        .wrap(Wrap { trim: true })
+        .line_count(width) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeline_has_a_bar_for_every_dated_entry() {
+        let entries = timeline_entries(Locale::English);
+        let studies = EDUCATION.iter().filter(|study| study.3.is_some()).count();
+        assert_eq!(entries.len(), EXPERIENCES.len() + studies);
+
+        // Roles come first, newest first, then the studies
+        let roles = &entries[..EXPERIENCES.len()];
+        assert!(roles.iter().all(|entry| entry.symbol == ROLE_SYMBOL));
+        assert!(roles
+            .windows(2)
+            .all(|pair| pair[0].range.start >= pair[1].range.start));
+        assert!(entries[EXPERIENCES.len()..]
+            .iter()
+            .all(|entry| entry.symbol == STUDY_SYMBOL));
+    }
+
+    #[test]
+    fn timeline_labels_follow_the_locale() {
+        let english = timeline_entries(Locale::English);
+        let turkish = timeline_entries(Locale::Turkish);
+        for ((entry, translated), role) in
+            english.iter().zip(&turkish).zip(experiences_newest_first())
+        {
+            assert_eq!(entry.label, role.1.get(Locale::English));
+            assert_eq!(translated.label, role.1.get(Locale::Turkish));
+        }
     }
 }
@@ -0,0 +1,198 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Widget};
+
+use crate::date::Date;
+use crate::i18n::{Locale, Message};
+use crate::ui::display_width;
+
+/// Symbols from no contributions to the busiest days.
+const SHADES: [&str; 5] = ["·", "░", "▒", "▓", "█"];
+
+/// Rows the widget needs, borders excluded: months, seven days and the legend.
+pub const HEATMAP_HEIGHT: u16 = 9;
+
+/// GitHub-style grid of daily counts, one column per week.
+///
+/// When the area is too narrow for every week, the most recent ones are shown.
+pub struct Heatmap<'a> {
+    counts: &'a [u16],
+    start: Date, // Day of `counts[0]`
+    block: Option<Block<'a>>,
+    style: Style,
+    locale: Locale,
+}
+
+impl<'a> Heatmap<'a> {
+    pub fn new(counts: &'a [u16], start: Date) -> Self {
+        Self {
+            counts,
+            start,
+            block: None,
+            style: Style::default(),
+            locale: Locale::default(),
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Language of the month and day labels and the legend.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Shade of a day, relative to the busiest day.
+    fn level(&self, count: u16, max: u16) -> usize {
+        if count == 0 || max == 0 {
+            return 0;
+        }
+        ((count as usize * 4).div_ceil(max as usize)).clamp(1, 4)
+    }
+}
+
+impl Widget for Heatmap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let area = match &self.block {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.clone().render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        if area.height < HEATMAP_HEIGHT || area.width < 8 {
+            return;
+        }
+
+        const GUTTER: u16 = 4; // Day labels
+        let offset = self.start.weekday() as usize; // Empty cells before the first day
+        let weeks = (self.counts.len() + offset).div_ceil(7);
+        let cell_width = if (area.width - GUTTER) as usize >= weeks * 2 {
+            2
+        } else {
+            1
+        };
+        let visible_weeks = weeks.min(((area.width - GUTTER) / cell_width) as usize);
+        let first_week = weeks - visible_weeks;
+        let max = self.counts.iter().copied().max().unwrap_or(0);
+
+        for row in [1, 3, 5] {
+            let label = self.locale.short_day_name(row as u32);
+            buf.set_string(area.x, area.y + 1 + row, label, self.style);
+        }
+
+        let mut label_end = area.x + GUTTER; // Month labels must not overlap
+        for week in first_week..weeks {
+            let x = area.x + GUTTER + (week - first_week) as u16 * cell_width;
+
+            // Label the week in which a month starts
+            let first_day = (week * 7).saturating_sub(offset);
+            let date = self.start.add_days(first_day as i64);
+            let previous = self.start.add_days(first_day as i64 - 7);
+            if (week == first_week || date.month != previous.month) && x >= label_end {
+                let name = self.locale.short_month_name(date.month);
+                let name_width = display_width(name);
+                if x + name_width <= area.right() {
+                    buf.set_string(x, area.y, name, self.style);
+                    label_end = x + name_width + 1;
+                }
+            }
+
+            for row in 0..7 {
+                let Some(index) = (week * 7 + row).checked_sub(offset) else {
+                    continue;
+                };
+                let Some(count) = self.counts.get(index) else {
+                    continue;
+                };
+                let shade = SHADES[self.level(*count, max)];
+                buf.set_string(x, area.y + 1 + row as u16, shade, self.style);
+            }
+        }
+
+        // Legend
+        let total: u32 = self.counts.iter().map(|count| *count as u32).sum();
+        let legend_y = area.y + 8;
+        buf.set_string(
+            area.x,
+            legend_y,
+            self.locale.format(Message::ContributionCount, total),
+            self.style,
+        );
+        let scale = format!(
+            "{} {} {}",
+            self.locale.message(Message::Less),
+            SHADES.join(" "),
+            self.locale.message(Message::More)
+        );
+        let scale_width = display_width(&scale);
+        if scale_width < area.width {
+            buf.set_string(area.right() - scale_width, legend_y, scale, self.style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered(locale: Locale) -> String {
+        let start = Date::parse("2026-01-01").unwrap();
+        let area = Rect::new(0, 0, 40, HEATMAP_HEIGHT);
+        let mut buf = Buffer::empty(area);
+        Heatmap::new(&[1, 0, 2, 3], start)
+            .locale(locale)
+            .render(area, &mut buf);
+        (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn labels_follow_the_locale() {
+        let english = rendered(Locale::English);
+        for label in [
+            "Jan",
+            "Mon",
+            "Wed",
+            "Fri",
+            "6 contributions",
+            "Less",
+            "More",
+        ] {
+            assert!(
+                english.contains(label),
+                "{:?} missing in\n{}",
+                label,
+                english
+            );
+        }
+
+        let turkish = rendered(Locale::Turkish);
+        for label in ["Oca", "Pzt", "Çar", "Cum", "6 katkı", "Az", "Çok"] {
+            assert!(
+                turkish.contains(label),
+                "{:?} missing in\n{}",
+                label,
+                turkish
+            );
+        }
+        assert!(!turkish.contains("contributions"));
+    }
+}
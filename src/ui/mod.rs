@@ -3,3 +3,21 @@ pub use components::*;
 
 pub mod views;
 pub use views::*;
+
+pub mod theme;
+pub use theme::*;
+
+pub mod heatmap;
+pub use heatmap::*;
+
+pub mod timeline;
+pub use timeline::*;
+
+pub mod width;
+pub use width::*;
+
+pub mod art;
+pub use art::*;
+
+pub mod backdrop;
+pub use backdrop::*;
@@ -0,0 +1,25 @@
+use ratatui::style::Color;
+
+pub struct Theme {
+    pub background: Color,
+    pub foreground: Color,
+    pub highlight: Color, // Selected tab and palette entry
+}
+
+pub const THEMES: &[Theme] = &[
+    Theme {
+        background: BG_COLOR,
+        foreground: Color::Rgb(220, 40, 50),
+        highlight: Color::Gray,
+    },
+    Theme {
+        background: Color::Rgb(245, 240, 230),
+        foreground: Color::Rgb(150, 20, 30),
+        highlight: Color::Rgb(60, 60, 60),
+    },
+];
+
+pub const BG_COLOR: Color = Color::Rgb(16, 24, 39);
+
+/// Background of the page around the panel, as set on `body` in `index.html`.
+pub const PAGE_COLOR: Color = Color::Rgb(18, 18, 18);
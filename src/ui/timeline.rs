@@ -0,0 +1,178 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Widget};
+
+use crate::date::{DateRange, Month};
+use crate::ui::{display_width, truncate_to_width};
+
+/// One bar of the timeline.
+pub struct TimelineEntry {
+    pub label: String,
+    pub range: DateRange,
+    pub symbol: &'static str, // Fills the bar, telling kinds of entries apart
+}
+
+/// Periods drawn as bars over a shared axis of years, one row per entry.
+///
+/// Ongoing periods run up to `today` and end with an arrow.
+pub struct Timeline<'a> {
+    entries: &'a [TimelineEntry],
+    today: Month,
+    block: Option<Block<'a>>,
+    style: Style,
+}
+
+impl<'a> Timeline<'a> {
+    pub fn new(entries: &'a [TimelineEntry], today: Month) -> Self {
+        Self {
+            entries,
+            today,
+            block: None,
+            style: Style::default(),
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Widget for Timeline<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let area = match &self.block {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.clone().render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        if self.entries.is_empty() || area.height < 3 || area.width < 16 {
+            return;
+        }
+
+        // Whole years, from January of the earliest start to December of the latest end
+        let Some(first_year) = self.entries.iter().map(|e| e.range.start.year).min() else {
+            return;
+        };
+        let last_year = self
+            .entries
+            .iter()
+            .map(|e| e.range.end_or(self.today).year)
+            .max()
+            .unwrap_or(first_year);
+        let first = Month {
+            year: first_year,
+            month: 1,
+        };
+        let months = (last_year - first_year + 1) * 12;
+
+        let label_width = self
+            .entries
+            .iter()
+            .map(|e| display_width(&e.label))
+            .max()
+            .unwrap_or(0)
+            .min(area.width / 3);
+        let axis_x = area.x + label_width + 1;
+        let axis_width = area.right() - axis_x;
+        // Column where a month starts
+        let column =
+            |month: i32| axis_x + ((month - first.index()) * axis_width as i32 / months) as u16;
+
+        let rows = (area.height - 2) as usize;
+        for (row, entry) in self.entries.iter().take(rows).enumerate() {
+            let y = area.y + row as u16;
+            buf.set_string(
+                area.x,
+                y,
+                truncate_to_width(&entry.label, label_width),
+                self.style,
+            );
+
+            let end = entry.range.end_or(self.today);
+            let start_x = column(entry.range.start.index());
+            let end_x = column(end.index() + 1).max(start_x + 1);
+            for x in start_x..end_x {
+                buf.set_string(x, y, entry.symbol, self.style);
+            }
+            if entry.range.end.is_none() {
+                buf.set_string(end_x - 1, y, "▶", self.style);
+            }
+        }
+
+        // Axis with a tick and a label at the start of every year
+        let axis_y = area.y + rows.min(self.entries.len()) as u16;
+        for x in axis_x..area.right() {
+            buf.set_string(x, axis_y, "─", self.style);
+        }
+        let mut label_end = axis_x;
+        for year in first_year..=last_year {
+            let x = column(year * 12);
+            buf.set_string(x, axis_y, "┼", self.style);
+            let label = year.to_string();
+            let label_width = display_width(&label);
+            if x >= label_end && x + label_width <= area.right() {
+                buf.set_string(x, axis_y + 1, &label, self.style);
+                label_end = x + label_width + 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(label: &str, range: &str) -> TimelineEntry {
+        TimelineEntry {
+            label: label.to_string(),
+            range: DateRange::parse(range).unwrap(),
+            symbol: "█",
+        }
+    }
+
+    /// Rows of the timeline drawn into `width` × `height` cells.
+    fn draw(entries: &[TimelineEntry], width: u16, height: u16) -> Vec<String> {
+        let area = Rect::new(0, 0, width, height);
+        let mut buffer = Buffer::empty(area);
+        let today = Month {
+            year: 2024,
+            month: 6,
+        };
+        Timeline::new(entries, today).render(area, &mut buffer);
+        (0..height)
+            .map(|y| (0..width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn long_labels_are_cut_to_a_third_of_the_width() {
+        let entries = [
+            entry("A very long role title", "Jan 2020 - Dec 2021"),
+            entry("Short", "Jan 2022 - Present"),
+        ];
+        let rows = draw(&entries, 30, 4);
+        assert!(rows[0].starts_with("A very lo… █"), "{:?}", rows);
+        assert!(rows[1].starts_with("Short      "), "{:?}", rows);
+        // The ongoing entry ends with an arrow
+        assert!(rows[1].contains('▶'), "{:?}", rows);
+    }
+
+    #[test]
+    fn labels_as_wide_as_the_longest_when_they_fit() {
+        let entries = [entry("日本", "Jan 2020 - Dec 2020")];
+        let rows = draw(&entries, 30, 3);
+        // The cell after a wide character stays blank
+        assert!(rows[0].starts_with("日 本  █"), "{:?}", rows);
+        assert!(rows[2].contains("2020"), "{:?}", rows);
+    }
+}
@@ -1,69 +1,148 @@
-use crate::data::FERRIS_RATATUI_AND_UNSAFE_FERRIS;
+use crate::actions::Action;
+use crate::backend::Hyperlink;
+use crate::data::{Project, FERRIS_ART};
+use crate::date::Month;
+use crate::i18n::{Locale, Message};
+use crate::photos::{RenderMode, PHOTOS};
+use crate::posts::Post;
+use crate::stats::{contributions_start, CONTRIBUTION_COUNTS};
+use crate::tabs::TabView;
 use crate::ui::{
-    render_contributions, render_education, render_experiences, render_projects,
-    render_publications, render_whoami,
+    display_width, figlet_header, pad_to_width, project_detail_text, render_contact_card,
+    render_contact_qr, render_scrollbar, render_text_pane, timeline_entries, wrapped_height,
+    ArtView, Heatmap, Theme, Timeline, HEATMAP_HEIGHT, ROLE_SYMBOL, STUDY_SYMBOL,
 };
 use gridlife::{CellState, Grid};
-use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
-use ratatui::prelude::{Color, Line, Style, Stylize, Text};
-use ratatui::widgets::{Block, BorderType, Clear, Paragraph};
+use ratatui::layout::{Alignment, Constraint, Direction, Flex, Layout, Margin, Rect};
+use ratatui::prelude::{Color, Line, Span, Style, Stylize, Text};
+use ratatui::widgets::{
+    Block, BorderType, Clear, List, ListItem, ListState, Paragraph, ScrollbarOrientation, Wrap,
+};
 use ratatui::Frame;
 
-pub const BG_COLOR: Color = Color::Rgb(16, 24, 39);
-
-pub fn render_about_me_and_education(frame: &mut Frame, content_area: Rect, scroll: u16) {
-    let content_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(14), // whoami
-            Constraint::Length(6),  // Education
-        ])
-        .split(content_area);
-
-    render_whoami(frame, content_chunks[0], scroll);
-    render_education(frame, content_chunks[1]);
+/// A bordered pane of wrapped text that scrolls on its own.
+pub struct TextPane {
+    pub title: Message,
+    pub text: fn(Locale) -> Text<'static>,
+    pub constraint: Constraint, // Height, borders included
 }
 
-pub fn render_projects_and_contributions(frame: &mut Frame, content_area: Rect, scroll: u16) {
-    let content_chunks = Layout::default()
+pub fn text_pane_areas(content_area: Rect, panes: &[TextPane]) -> Vec<Rect> {
+    Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(14), // Projects
-            Constraint::Length(6),  // Publications
-        ])
-        .split(content_area);
-
-    render_projects(frame, content_chunks[0], scroll);
-    render_contributions(frame, content_chunks[1]);
+        .constraints(panes.iter().map(|pane| pane.constraint))
+        .split(content_area)
+        .to_vec()
 }
 
-pub fn render_experiences_and_publications(frame: &mut Frame, content_area: Rect, scroll: u16) {
-    let content_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(14), // Experience
-            Constraint::Length(6),  // Publications
-        ])
-        .split(content_area);
+/// Rows `text` overflows a bordered pane of the given area by.
+pub fn text_max_scroll(text: &Text<'_>, pane_area: Rect) -> u16 {
+    wrapped_height(text, pane_area.width.saturating_sub(2))
+        .saturating_sub(pane_area.height.saturating_sub(2))
+}
 
-    render_experiences(frame, content_chunks[0], scroll);
-    render_publications(frame, content_chunks[1]);
+pub fn render_text_panes(
+    frame: &mut Frame,
+    content_area: Rect,
+    panes: &[TextPane],
+    view: &TabView,
+) {
+    for (i, (pane, area)) in panes
+        .iter()
+        .zip(text_pane_areas(content_area, panes))
+        .enumerate()
+    {
+        let focused = i == view.focused_pane && panes.len() > 1;
+        render_text_pane(
+            frame,
+            area,
+            view.locale.message(pane.title),
+            (pane.text)(view.locale),
+            view.scroll[i],
+            focused,
+            view.theme,
+        );
+        render_scrollbar(
+            frame,
+            area,
+            view.scroll[i],
+            view.max_scroll[i],
+            ScrollbarOrientation::VerticalRight,
+        );
+    }
 }
 
 pub fn render_ferris_ratatui_and_unsafe_ferris(
     frame: &mut Frame,
     long_live_ferris: Rect,
     scroll: u16,
+    max_scroll: u16,
+    locale: Locale,
 ) {
     frame.render_widget(
-        Paragraph::new(FERRIS_RATATUI_AND_UNSAFE_FERRIS)
-            .left_aligned()
-            .scroll((0, scroll))
-            .block(
-                Block::bordered().title("Ferris, Ratatui and Unsafe Ferris".bold().underlined()),
-            ),
+        ArtView::new(&FERRIS_ART)
+            .scroll(scroll)
+            .block(Block::bordered().title(locale.message(Message::FerrisArt).bold().underlined())),
         long_live_ferris,
     );
+    render_scrollbar(
+        frame,
+        long_live_ferris,
+        scroll,
+        max_scroll,
+        ScrollbarOrientation::HorizontalBottom,
+    );
+}
+
+/// Splits the contact tab into the QR code and the contact card.
+pub fn contact_areas(content_area: Rect, qr: &[Line<'static>]) -> [Rect; 2] {
+    let qr_width = qr.first().map_or(0, |line| line.width()) as u16;
+    Layout::horizontal([
+        Constraint::Length(qr_width + 2), // QR code
+        Constraint::Min(0),               // Contact card
+    ])
+    .areas(content_area)
+}
+
+pub fn render_contact(frame: &mut Frame, content_area: Rect, qr: &[Line<'static>], view: &TabView) {
+    let (scroll, max_scroll) = (view.scroll[0], view.max_scroll[0]);
+    let [qr_area, card_area] = contact_areas(content_area, qr);
+    let hint = view.keymap.hint(
+        Message::ContactHint,
+        &[Action::DownloadContact],
+        view.locale,
+    );
+
+    render_contact_qr(frame, qr_area, qr, &hint);
+    render_contact_card(frame, card_area, scroll, view.locale);
+    render_scrollbar(
+        frame,
+        card_area,
+        scroll,
+        max_scroll,
+        ScrollbarOrientation::VerticalRight,
+    );
+}
+
+/// The boot sequence typed so far, filling the screen like a console would,
+/// with a cursor after the last character.
+pub fn render_boot(frame: &mut Frame<'_>, screen: &[String]) {
+    let area = frame.area().inner(Margin::new(1, 1));
+    let mut lines: Vec<Line> = screen
+        .iter()
+        .map(|line| Line::from(line.as_str()))
+        .collect();
+    if let Some(last) = lines.last_mut() {
+        last.push_span(Span::raw("█"));
+    }
+    // Older lines scroll off the top once the screen is full
+    let scroll = (lines.len() as u16).saturating_sub(area.height);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .fg(Color::Rgb(190, 190, 190))
+            .scroll((scroll, 0)),
+        area,
+    );
 }
 
 pub fn render_game_of_life(grid: &mut Grid<CellState>, frame: &mut Frame<'_>) {
@@ -74,13 +153,10 @@ pub fn render_game_of_life(grid: &mut Grid<CellState>, frame: &mut Frame<'_>) {
     frame.render_widget(Paragraph::new(grid_text), frame.area());
 }
 
-pub fn render_background(
-    frame: &mut Frame<'_>,
-    area: Rect,
-    title: Option<String>,
-    constraints: &[Constraint],
-) {
-    let mut area = Rect::new(
+/// The panel around the sections of `area` laid out by `constraints`, kept
+/// inside `bounds`.
+pub fn panel_area(area: Rect, constraints: &[Constraint], bounds: Rect) -> Rect {
+    Rect::new(
         area.x - 2,
         area.y - 1,
         area.width + 4,
@@ -92,16 +168,22 @@ pub fn render_background(
             })
             .sum::<u16>()
             + 3,
-    );
-    area = area.clamp(frame.area());
+    )
+    .clamp(bounds)
+}
+
+pub fn render_background(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    title: Option<String>,
+    constraints: &[Constraint],
+    theme: &Theme,
+) {
+    let area = panel_area(area, constraints, frame.area());
     let mut block = Block::bordered()
         .border_type(BorderType::Rounded)
-        .border_style(Color::Rgb(220, 40, 50))
-        .style(
-            Style::default()
-                .fg(Color::Rgb(220, 40, 50))
-                .bg(Color::Rgb(16, 24, 39)),
-        )
+        .border_style(theme.foreground)
+        .style(Style::default().fg(theme.foreground).bg(theme.background))
         .title_bottom("|built with Ratzilla|")
         .title_alignment(Alignment::Center);
     if let Some(title) = title {
@@ -110,3 +192,309 @@ pub fn render_background(
     frame.render_widget(Clear, area);
     frame.render_widget(block, area);
 }
+
+/// Splits the inside of the reader into the title drawn as a header, when one
+/// fits, and the scrolling body.
+fn post_areas(content_area: Rect, header: &[String]) -> [Rect; 2] {
+    let header_height = match header.len() {
+        0 => 0,
+        rows => rows as u16 + 1, // A blank row before the body
+    };
+    Layout::vertical([Constraint::Length(header_height), Constraint::Fill(1)])
+        .areas(Block::bordered().inner(content_area))
+}
+
+/// Rows a post overflows the reader pane by.
+///
+/// Posts keep their leading whitespace so code blocks stay indented.
+pub fn post_max_scroll(post: &Post, body: &Text<'static>, content_area: Rect) -> u16 {
+    let header = figlet_header(post.title, content_area.width.saturating_sub(2));
+    let [_, body_area] = post_areas(content_area, &header);
+    let height = Paragraph::new(body.clone())
+        .wrap(Wrap { trim: false })
+        .line_count(body_area.width) as u16;
+    height.saturating_sub(body_area.height)
+}
+
+/// Row of the reader body a line of the post starts at.
+///
+/// The header stays put above the body, so rows count from the top of the body.
+pub fn post_line_row(post: &Post, body: &Text<'_>, content_area: Rect, line: usize) -> u16 {
+    let header = figlet_header(post.title, content_area.width.saturating_sub(2));
+    let [_, body_area] = post_areas(content_area, &header);
+    let before = Text::from(body.lines[..line.min(body.lines.len())].to_vec());
+    Paragraph::new(before)
+        .wrap(Wrap { trim: false })
+        .line_count(body_area.width) as u16
+}
+
+pub fn render_post(
+    frame: &mut Frame,
+    content_area: Rect,
+    post: &Post,
+    body: &Text<'static>,
+    view: &TabView,
+) {
+    let block = Block::bordered()
+        .title(post.title.bold().underlined())
+        .title(Line::from(post.date).right_aligned())
+        .title_bottom(
+            Line::from(
+                view.keymap
+                    .hint(Message::BackHint, &[Action::Back], view.locale),
+            )
+            .right_aligned(),
+        );
+    let header = figlet_header(post.title, block.inner(content_area).width);
+    let [header_area, body_area] = post_areas(content_area, &header);
+    frame.render_widget(block, content_area);
+    frame.render_widget(
+        Paragraph::new(header.into_iter().map(Line::from).collect::<Vec<_>>())
+            .style(Style::default().fg(view.theme.highlight)),
+        header_area,
+    );
+    frame.render_widget(
+        Paragraph::new(body.clone())
+            .wrap(Wrap { trim: false })
+            .scroll((view.scroll[0], 0)),
+        body_area,
+    );
+    render_scrollbar(
+        frame,
+        content_area,
+        view.scroll[0],
+        view.max_scroll[0],
+        ScrollbarOrientation::VerticalRight,
+    );
+}
+
+/// Bordered list of entries with a cursor, highlighted while it has the focus.
+pub fn render_list_pane(
+    frame: &mut Frame,
+    pane_area: Rect,
+    title: &str,
+    hint: &str,
+    items: Vec<ListItem<'static>>,
+    focused: bool,
+    view: &TabView,
+) {
+    let selected = view.selection.selected;
+    let overflows = items.iter().map(ListItem::height).sum::<usize>()
+        > pane_area.height.saturating_sub(2) as usize;
+    let entries = items.len();
+
+    let mut block = Block::bordered()
+        .title(title.bold().underlined())
+        .title_bottom(Line::from(hint.to_string()).right_aligned());
+    if focused {
+        block = block.border_style(Style::default().fg(view.theme.highlight));
+    }
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(
+        List::new(items)
+            .highlight_style(Style::default().fg(view.theme.highlight))
+            .highlight_symbol("> ")
+            .block(block),
+        pane_area,
+        &mut state,
+    );
+    // The thumb follows the cursor rather than the rows
+    if overflows {
+        render_scrollbar(
+            frame,
+            pane_area,
+            selected as u16,
+            entries.saturating_sub(1) as u16,
+            ScrollbarOrientation::VerticalRight,
+        );
+    }
+}
+
+/// Columns left for the text of a list entry inside a bordered pane.
+pub fn list_text_width(pane_area: Rect) -> u16 {
+    pane_area.width.saturating_sub(4) // Borders and cursor
+}
+
+/// Splits the projects tab into its panes and the contribution heatmap below them,
+/// which takes no room without contributions.
+pub fn projects_areas(content_area: Rect) -> [Rect; 2] {
+    let heatmap_height = match contributions_start() {
+        Some(_) => HEATMAP_HEIGHT + 2,
+        None => 0,
+    };
+    Layout::vertical([Constraint::Fill(1), Constraint::Length(heatmap_height)]).areas(content_area)
+}
+
+pub fn render_contribution_heatmap(
+    frame: &mut Frame,
+    heatmap_area: Rect,
+    theme: &Theme,
+    locale: Locale,
+) {
+    let Some(start) = contributions_start() else {
+        return;
+    };
+    let title = locale.message(Message::ContributionActivity);
+    frame.render_widget(
+        Heatmap::new(CONTRIBUTION_COUNTS, start)
+            .style(Style::default().fg(theme.foreground))
+            .locale(locale)
+            .block(Block::bordered().title(title.bold().underlined())),
+        heatmap_area,
+    );
+}
+
+/// Experiences and education on one axis, in place of the experience list.
+pub fn render_timeline_pane(frame: &mut Frame, pane_area: Rect, view: &TabView) {
+    let (theme, locale) = (view.theme, view.locale);
+    let entries = timeline_entries(locale);
+    // Only the kinds of bars that are drawn
+    let mut legend = vec![format!(
+        "{} {}",
+        ROLE_SYMBOL,
+        locale.message(Message::TimelineWork)
+    )];
+    if entries.iter().any(|entry| entry.symbol == STUDY_SYMBOL) {
+        legend.push(format!(
+            "{} {}",
+            STUDY_SYMBOL,
+            locale.message(Message::TimelineEducation)
+        ));
+    }
+    legend.push(
+        view.keymap
+            .hint(Message::ListHint, &[Action::ToggleTimeline], locale),
+    );
+    let mut block = Block::bordered()
+        .title(locale.message(Message::Timeline).bold().underlined())
+        .title_bottom(Line::from(legend.join("  ")).right_aligned());
+    if view.focused_pane == 0 {
+        block = block.border_style(Style::default().fg(theme.highlight));
+    }
+    frame.render_widget(
+        Timeline::new(&entries, Month::current())
+            .style(Style::default().fg(theme.foreground))
+            .block(block),
+        pane_area,
+    );
+}
+
+/// Inside of the photo pane, the size `text` is drawn for.
+pub fn photo_pane_inner(pane_area: Rect) -> Rect {
+    Block::bordered().inner(pane_area)
+}
+
+/// The photo at `index` of the gallery, drawn in `mode` as `text` and centered in
+/// its pane, or a note while the gallery is empty.
+pub fn render_photo_pane(
+    frame: &mut Frame,
+    pane_area: Rect,
+    index: usize,
+    mode: RenderMode,
+    text: &Text<'static>,
+    view: &TabView,
+) {
+    let locale = view.locale;
+    let inner = photo_pane_inner(pane_area);
+    let block = Block::bordered().border_style(Style::default().fg(view.theme.highlight));
+    let Some(photo) = PHOTOS.get(index) else {
+        let [note_area] = Layout::vertical([Constraint::Length(1)])
+            .flex(Flex::Center)
+            .areas(inner);
+        frame.render_widget(
+            block.title(locale.message(Message::Photos).bold().underlined()),
+            pane_area,
+        );
+        frame.render_widget(
+            Paragraph::new(locale.message(Message::NoPhotos)).centered(),
+            note_area,
+        );
+        return;
+    };
+    let block = block
+        .title(photo.caption.get(locale).bold().underlined())
+        .title(Line::from(format!("{}/{}", index + 1, PHOTOS.len())).right_aligned())
+        .title_bottom(Line::from(locale.message(mode.label())).left_aligned())
+        .title_bottom(
+            Line::from(view.keymap.hint(
+                Message::GalleryHint,
+                &[
+                    Action::PreviousEntry,
+                    Action::NextEntry,
+                    Action::CycleRenderMode,
+                ],
+                locale,
+            ))
+            .right_aligned(),
+        );
+    frame.render_widget(block, pane_area);
+
+    let [photo_area] = Layout::vertical([Constraint::Length(text.height() as u16)])
+        .flex(Flex::Center)
+        .areas(inner);
+    frame.render_widget(Paragraph::new(text.clone()).centered(), photo_area);
+}
+
+/// Splits the project detail view into the write-up and its links.
+pub fn project_detail_areas(content_area: Rect, project: &Project) -> [Rect; 2] {
+    let link_count = 1 + project.crate_name.iter().count() as u16;
+    Layout::vertical([Constraint::Fill(1), Constraint::Length(link_count + 2)]).areas(content_area)
+}
+
+pub fn render_project_detail(
+    frame: &mut Frame,
+    content_area: Rect,
+    project: &Project,
+    view: &TabView,
+) {
+    let (scroll, max_scroll, locale) = (view.scroll[0], view.max_scroll[0], view.locale);
+    let [text_area, links_area] = project_detail_areas(content_area, project);
+
+    render_text_pane(
+        frame,
+        text_area,
+        project.name,
+        project_detail_text(project, locale),
+        scroll,
+        false,
+        view.theme,
+    );
+    render_scrollbar(
+        frame,
+        text_area,
+        scroll,
+        max_scroll,
+        ScrollbarOrientation::VerticalRight,
+    );
+
+    frame.render_widget(
+        Block::bordered()
+            .title(locale.message(Message::Links).bold().underlined())
+            .title_bottom(
+                Line::from(view.keymap.hint(Message::BackHint, &[Action::Back], locale))
+                    .right_aligned(),
+            ),
+        links_area,
+    );
+    let mut links = vec![(
+        locale.message(Message::Repository),
+        project.repository.to_string(),
+    )];
+    links.extend(project.crates_io_url().map(|url| ("crates.io", url)));
+    for (i, (name, url)) in links.into_iter().enumerate() {
+        let row = Rect::new(
+            links_area.x + 1,
+            links_area.y + 1 + i as u16,
+            links_area.width.saturating_sub(2),
+            1,
+        );
+        let label = pad_to_width(name, 12, Alignment::Left);
+        let [label_area, link_area] = Layout::horizontal([
+            Constraint::Length(display_width(&label)),
+            Constraint::Fill(1),
+        ])
+        .areas(row);
+        frame.render_widget(Span::styled(label, Style::default().bold()), label_area);
+        frame.render_widget(Hyperlink::new(url), link_area);
+    }
+}
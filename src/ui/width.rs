@@ -0,0 +1,148 @@
+use ratatui::layout::Alignment;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Columns `text` takes up on screen, wide characters such as CJK and emoji counting
+/// twice and combining marks not at all.
+pub fn display_width(text: &str) -> u16 {
+    text.width().min(u16::MAX as usize) as u16
+}
+
+/// Columns of the widest line of `text`.
+pub fn block_width(text: &str) -> u16 {
+    text.lines().map(display_width).max().unwrap_or(0)
+}
+
+/// Rows of `text`, the trailing newline of a raw string not counting as a row.
+pub fn block_height(text: &str) -> u16 {
+    text.lines().count() as u16
+}
+
+/// `text` cut to fit in `width` columns, ending with `…` when something was cut.
+///
+/// A wide character that would straddle the edge is dropped rather than split,
+/// and a character is kept whole with its combining marks and joined emoji.
+pub fn truncate_to_width(text: &str, width: u16) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+    let available = width.saturating_sub(1); // Room for the ellipsis
+    let mut used = 0;
+    let mut cut = String::new();
+    for grapheme in text.graphemes(true) {
+        let grapheme_width = display_width(grapheme);
+        if used + grapheme_width > available {
+            break;
+        }
+        used += grapheme_width;
+        cut.push_str(grapheme);
+    }
+    if width > 0 {
+        cut.push('…');
+    }
+    cut
+}
+
+/// `text` padded with spaces to `width` columns, on the side `alignment` leaves free.
+pub fn pad_to_width(text: &str, width: u16, alignment: Alignment) -> String {
+    let padding = width.saturating_sub(display_width(text)) as usize;
+    match alignment {
+        Alignment::Left => format!("{}{}", text, " ".repeat(padding)),
+        Alignment::Right => format!("{}{}", " ".repeat(padding), text),
+        Alignment::Center => format!(
+            "{}{}{}",
+            " ".repeat(padding / 2),
+            text,
+            " ".repeat(padding - padding / 2)
+        ),
+    }
+}
+
+/// The columns of `text` from `start` on, at most `width` of them.
+///
+/// Wide characters cut by either edge are left out.
+pub fn slice_columns(text: &str, start: u16, width: u16) -> String {
+    let end = start.saturating_add(width);
+    let mut column = 0;
+    let mut slice = String::new();
+    for grapheme in text.graphemes(true) {
+        let grapheme_width = display_width(grapheme);
+        if column >= start && column + grapheme_width <= end {
+            slice.push_str(grapheme);
+        }
+        column += grapheme_width;
+        if column >= end {
+            break;
+        }
+    }
+    slice
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CJK: &str = "日本語";
+    const ZWJ_EMOJI: &str = "👩‍💻"; // Woman, zero width joiner, laptop
+    const COMBINING: &str = "e\u{301}te\u{301}"; // "été" with combining acutes
+
+    #[test]
+    fn display_width_counts_columns() {
+        assert_eq!(display_width("ascii"), 5);
+        assert_eq!(display_width(CJK), 6);
+        assert_eq!(display_width(ZWJ_EMOJI), 2);
+        assert_eq!(display_width(COMBINING), 3);
+        assert_eq!(display_width(""), 0);
+    }
+
+    #[test]
+    fn block_size_uses_the_widest_line() {
+        assert_eq!(block_width("ab\n日本\nc\n"), 4);
+        assert_eq!(block_height("ab\n日本\nc\n"), 3);
+    }
+
+    #[test]
+    fn truncation_fits_the_width() {
+        assert_eq!(truncate_to_width("hello", 5), "hello");
+        assert_eq!(truncate_to_width("hello", 4), "hel…");
+        assert_eq!(truncate_to_width("hello", 0), "");
+        assert_eq!(truncate_to_width(COMBINING, 3), COMBINING);
+        assert_eq!(truncate_to_width(COMBINING, 2), "e\u{301}…");
+    }
+
+    #[test]
+    fn truncation_drops_a_straddling_wide_character() {
+        assert_eq!(truncate_to_width(CJK, 5), "日本…");
+        assert_eq!(truncate_to_width(CJK, 4), "日…");
+        assert_eq!(display_width(&truncate_to_width(CJK, 4)), 3);
+        assert_eq!(truncate_to_width(ZWJ_EMOJI, 2), ZWJ_EMOJI);
+        assert_eq!(truncate_to_width("a👩‍💻", 2), "a…");
+        assert_eq!(truncate_to_width("👩‍💻xy", 3), "👩‍💻…");
+    }
+
+    #[test]
+    fn padding_fills_the_free_side() {
+        assert_eq!(pad_to_width("日本", 6, Alignment::Left), "日本  ");
+        assert_eq!(pad_to_width("日本", 6, Alignment::Right), "  日本");
+        assert_eq!(pad_to_width("日", 5, Alignment::Center), " 日  ");
+        assert_eq!(
+            pad_to_width(COMBINING, 4, Alignment::Left),
+            format!("{} ", COMBINING)
+        );
+        assert_eq!(
+            pad_to_width(ZWJ_EMOJI, 3, Alignment::Right),
+            format!(" {}", ZWJ_EMOJI)
+        );
+        assert_eq!(pad_to_width("toolong", 3, Alignment::Left), "toolong");
+    }
+
+    #[test]
+    fn slices_leave_out_cut_wide_characters() {
+        assert_eq!(slice_columns("abcdef", 2, 3), "cde");
+        assert_eq!(slice_columns(CJK, 2, 4), "本語");
+        assert_eq!(slice_columns(CJK, 1, 4), "本");
+        assert_eq!(slice_columns(COMBINING, 1, 2), "te\u{301}");
+        assert_eq!(slice_columns("a👩‍💻b", 1, 3), "👩‍💻b");
+        assert_eq!(slice_columns("a👩‍💻b", 2, 2), "b");
+    }
+}
@@ -0,0 +1,130 @@
+use ratzilla::event::KeyEvent;
+use ratzilla::ratatui::layout::Position;
+use web_sys::wasm_bindgen::prelude::Closure;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlAnchorElement, KeyboardEvent, MouseEvent};
+
+/// Makes the browser download `contents` as a file named `file_name`.
+pub fn download_file(file_name: &str, mime: &str, contents: &str) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let Ok(anchor) = document
+        .create_element("a")
+        .map(|e| e.unchecked_into::<HtmlAnchorElement>())
+    else {
+        return;
+    };
+    anchor.set_href(&format!(
+        "data:{};charset=utf-8,{}",
+        mime,
+        percent_encode(contents)
+    ));
+    anchor.set_download(file_name);
+    anchor.click();
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Value an earlier visit saved under `key` in `localStorage`.
+pub fn load(key: &str) -> Option<String> {
+    web_sys::window()?
+        .local_storage()
+        .ok()??
+        .get_item(key)
+        .ok()?
+}
+
+/// Saves `value` under `key` in `localStorage`, for later visits.
+pub fn save(key: &str, value: &str) {
+    if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok()?) {
+        let _ = storage.set_item(key, value);
+    }
+}
+
+/// Whether the visitor asked the browser for as little motion as possible.
+pub fn prefers_reduced_motion() -> bool {
+    web_sys::window()
+        .and_then(|window| {
+            window
+                .match_media("(prefers-reduced-motion: reduce)")
+                .ok()?
+        })
+        .is_some_and(|query| query.matches())
+}
+
+/// Fragment of the page URL without the `#`, e.g. `posts/hello` for links from the feeds.
+pub fn location_hash() -> Option<String> {
+    let hash = web_sys::window()?.location().hash().ok()?;
+    let hash = hash.trim_start_matches('#');
+    (!hash.is_empty()).then(|| hash.to_string())
+}
+
+/// The element the backend draws the cells into, a `<pre>` for each row.
+fn grid() -> Option<Element> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id("grid"))
+}
+
+/// Calls `callback` with the cell under the mouse pointer whenever it moves,
+/// `None` when it isn't over a cell.
+pub fn on_mouse_move(mut callback: impl FnMut(Option<Position>) + 'static) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let closure = Closure::<dyn FnMut(_)>::new(move |event: MouseEvent| {
+        callback(pointed_cell(&event));
+    });
+    let _ =
+        document.add_event_listener_with_callback("mousemove", closure.as_ref().unchecked_ref());
+    closure.forget();
+}
+
+/// Position of the cell whose `<span>` the event happened on.
+fn pointed_cell(event: &MouseEvent) -> Option<Position> {
+    let span = event.target()?.dyn_into::<Element>().ok()?;
+    if span.tag_name() != "SPAN" {
+        return None;
+    }
+    let row = span.closest("pre").ok()??;
+    let grid = grid()?;
+    let rows = grid.children();
+    let y = (0..rows.length()).find(|&y| rows.item(y).as_ref() == Some(&row))?;
+    let cells = row.query_selector_all("span").ok()?;
+    let x = (0..cells.length()).find(|&x| cells.item(x).as_ref() == Some(span.as_ref()))?;
+    Some(Position::new(x as u16, y as u16))
+}
+
+/// Stops the browser's own shortcut for every key press `handled` claims, such
+/// as Ctrl-d bookmarking the page.
+///
+/// The listener captures on the window, so it runs before the key reaches
+/// Ratzilla's handler on the document, which still gets it.
+pub fn prevent_default_keys(handled: impl Fn(&KeyEvent) -> bool + 'static) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let closure = Closure::<dyn FnMut(_)>::new(move |event: KeyboardEvent| {
+        if handled(&event.clone().into()) {
+            event.prevent_default();
+        }
+    });
+    let _ = window.add_event_listener_with_callback_and_bool(
+        "keydown",
+        closure.as_ref().unchecked_ref(),
+        true,
+    );
+    closure.forget();
+}
@@ -0,0 +1,79 @@
+//! The stats parsers of the build script, against the fixtures in
+//! `tests/fixtures/stats/`.
+
+use std::fs;
+
+#[path = "../tools/front_matter.rs"]
+#[allow(dead_code)] // Only the date check is used
+mod front_matter;
+
+#[path = "../tools/stats.rs"]
+mod stats;
+
+fn read_fixture(name: &str) -> String {
+    let path = format!("tests/fixtures/stats/{}", name);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+fn parse_fixture(name: &str) -> Result<stats::Snapshot, String> {
+    stats::parse_snapshot(&read_fixture(name))
+}
+
+fn parse_error(name: &str) -> String {
+    match parse_fixture(name) {
+        Ok(_) => panic!("{} parsed", name),
+        Err(error) => error,
+    }
+}
+
+#[test]
+fn parses_a_snapshot() {
+    let snapshot = parse_fixture("snapshot.json").unwrap();
+    assert_eq!(snapshot.fetched_at, "2026-01-31");
+
+    let crate_stats = &snapshot.crates["example-crate"];
+    assert_eq!(crate_stats.downloads, 1234);
+    assert_eq!(crate_stats.version, "1.2.3");
+
+    assert_eq!(snapshot.repositories.len(), 2);
+    assert_eq!(snapshot.repositories["example/example-crate"].stars, 56);
+    assert_eq!(snapshot.repositories["example/other"].stars, 0);
+}
+
+#[test]
+fn rejects_missing_fields() {
+    assert!(parse_error("missing-downloads.json").contains("missing field `downloads`"));
+    assert!(parse_error("missing-fetched-at.json").contains("missing field `fetched_at`"));
+}
+
+#[test]
+fn rejects_malformed_fields() {
+    assert!(parse_error("negative-stars.json").contains("invalid value: integer `-1`"));
+    assert!(parse_error("unknown-field.json").contains("unknown field `recent_downloads`"));
+    assert_eq!(
+        parse_error("bad-date.json"),
+        "fetched_at: `2026-02-30` is not a YYYY-MM-DD date"
+    );
+}
+
+#[test]
+fn rejects_truncated_json() {
+    assert!(parse_error("truncated.json").contains("EOF while parsing"));
+}
+
+#[test]
+fn parses_contributions() {
+    let contributions = stats::parse_contributions(&read_fixture("contributions.json")).unwrap();
+    assert_eq!(contributions.from, "2026-01-29");
+    assert_eq!(contributions.counts, [0, 3, 1, 0]);
+}
+
+#[test]
+fn rejects_broken_contributions() {
+    let parse = |name| match stats::parse_contributions(&read_fixture(name)) {
+        Ok(_) => panic!("{} parsed", name),
+        Err(error) => error,
+    };
+    assert!(parse("contributions-missing-counts.json").contains("missing field `counts`"));
+    assert!(parse("contributions-bad-count.json").contains("invalid type: string \"many\""));
+}
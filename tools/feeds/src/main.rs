@@ -0,0 +1,334 @@
+//! Writes the Atom (`feed.xml`) and RSS (`rss.xml`) feeds of the posts and projects.
+//!
+//! Runs natively after `trunk build`, writing into the directory it is given:
+//!
+//! ```text
+//! cargo run -p feeds -- dist
+//! ```
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use pulldown_cmark::{html, Event, Parser, TagEnd};
+use serde::Deserialize;
+
+#[path = "../../front_matter.rs"]
+mod front_matter;
+
+#[path = "../../../src/data/projects.rs"]
+mod projects;
+
+use front_matter::calendar::Date;
+
+#[derive(Deserialize)]
+struct Content {
+    site: Site,
+    projects: Projects,
+}
+
+#[derive(Deserialize)]
+struct Site {
+    title: String,
+    description: String,
+    author: String,
+    email: String,
+    base_url: String,
+}
+
+#[derive(Deserialize)]
+struct Projects {
+    updated: String,
+}
+
+/// Date of an empty feed.
+const EPOCH: Date = Date {
+    year: 1970,
+    month: 1,
+    day: 1,
+};
+
+/// A post or project, the way both feed formats need it.
+struct Entry {
+    title: String,
+    url: String,
+    date: Date,
+    categories: Vec<String>,
+    summary: String,
+    html: Option<String>,
+}
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let out_dir = PathBuf::from(env::args().nth(1).unwrap_or_else(|| "dist".to_string()));
+
+    let config =
+        fs::read_to_string(root.join("content.toml")).expect("failed to read content.toml");
+    let content: Content = toml::from_str(&config).expect("invalid content.toml");
+    let base_url = content.site.base_url.trim_end_matches('/');
+    let projects_updated = parse_date(&content.projects.updated, "projects.updated")
+        .unwrap_or_else(|e| panic!("invalid content.toml: {}", e));
+
+    let mut entries: Vec<Entry> = front_matter::read_posts(&root.join("posts"))
+        .into_iter()
+        .map(|post| {
+            let mut html = String::new();
+            html::push_html(&mut html, Parser::new(&post.markdown));
+            Entry {
+                title: post.front_matter.title,
+                url: format!("{}/#posts/{}", base_url, post.slug),
+                date: parse_date(&post.front_matter.date, "date")
+                    .unwrap_or_else(|e| panic!("invalid posts/{}.md: {}", post.slug, e)),
+                categories: post.front_matter.tags,
+                summary: first_paragraph(&post.markdown),
+                html: Some(html),
+            }
+        })
+        .collect();
+    entries.extend(projects::PROJECTS.iter().map(|project| Entry {
+        title: project.name.to_string(),
+        url: format!("{}/#projects/{}", base_url, project.name.to_lowercase()),
+        date: projects_updated,
+        categories: project.tags.iter().map(|tag| tag.to_string()).collect(),
+        summary: project.description.to_string(),
+        html: Some(project_html(project)),
+    }));
+    // Newest first, posts before projects of the same day
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.date));
+
+    fs::create_dir_all(&out_dir).expect("failed to create the output directory");
+    fs::write(
+        out_dir.join("feed.xml"),
+        atom(&content.site, base_url, &entries),
+    )
+    .expect("failed to write feed.xml");
+    fs::write(
+        out_dir.join("rss.xml"),
+        rss(&content.site, base_url, &entries),
+    )
+    .expect("failed to write rss.xml");
+}
+
+fn atom(site: &Site, base_url: &str, entries: &[Entry]) -> String {
+    let updated = entries.iter().map(|entry| entry.date).max();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str(&format!("  <title>{}</title>\n", escape(&site.title)));
+    xml.push_str(&format!(
+        "  <subtitle>{}</subtitle>\n",
+        escape(&site.description)
+    ));
+    xml.push_str(&format!(
+        "  <link href=\"{}/feed.xml\" rel=\"self\"/>\n",
+        escape(base_url)
+    ));
+    xml.push_str(&format!("  <link href=\"{}/\"/>\n", escape(base_url)));
+    xml.push_str(&format!("  <id>{}/</id>\n", escape(base_url)));
+    xml.push_str(&format!(
+        "  <updated>{}</updated>\n",
+        rfc3339(updated.unwrap_or(EPOCH))
+    ));
+    xml.push_str(&format!(
+        "  <author>\n    <name>{}</name>\n    <email>{}</email>\n  </author>\n",
+        escape(&site.author),
+        escape(&site.email)
+    ));
+    for entry in entries {
+        xml.push_str("  <entry>\n");
+        xml.push_str(&format!("    <title>{}</title>\n", escape(&entry.title)));
+        xml.push_str(&format!("    <link href=\"{}\"/>\n", escape(&entry.url)));
+        xml.push_str(&format!("    <id>{}</id>\n", escape(&entry.url)));
+        xml.push_str(&format!("    <updated>{}</updated>\n", rfc3339(entry.date)));
+        for category in &entry.categories {
+            xml.push_str(&format!("    <category term=\"{}\"/>\n", escape(category)));
+        }
+        xml.push_str(&format!(
+            "    <summary>{}</summary>\n",
+            escape(&entry.summary)
+        ));
+        if let Some(html) = &entry.html {
+            xml.push_str(&format!(
+                "    <content type=\"html\">{}</content>\n",
+                escape(html)
+            ));
+        }
+        xml.push_str("  </entry>\n");
+    }
+    xml.push_str("</feed>\n");
+    xml
+}
+
+fn rss(site: &Site, base_url: &str, entries: &[Entry]) -> String {
+    let updated = entries.iter().map(|entry| entry.date).max();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str("  <channel>\n");
+    xml.push_str(&format!("    <title>{}</title>\n", escape(&site.title)));
+    xml.push_str(&format!("    <link>{}/</link>\n", escape(base_url)));
+    xml.push_str(&format!(
+        "    <description>{}</description>\n",
+        escape(&site.description)
+    ));
+    xml.push_str(&format!(
+        "    <atom:link href=\"{}/rss.xml\" rel=\"self\" type=\"application/rss+xml\"/>\n",
+        escape(base_url)
+    ));
+    xml.push_str(&format!(
+        "    <lastBuildDate>{}</lastBuildDate>\n",
+        rfc822(updated.unwrap_or(EPOCH))
+    ));
+    for entry in entries {
+        xml.push_str("    <item>\n");
+        xml.push_str(&format!("      <title>{}</title>\n", escape(&entry.title)));
+        xml.push_str(&format!("      <link>{}</link>\n", escape(&entry.url)));
+        xml.push_str(&format!(
+            "      <guid isPermaLink=\"true\">{}</guid>\n",
+            escape(&entry.url)
+        ));
+        xml.push_str(&format!(
+            "      <pubDate>{}</pubDate>\n",
+            rfc822(entry.date)
+        ));
+        xml.push_str(&format!(
+            "      <author>{} ({})</author>\n",
+            escape(&site.email),
+            escape(&site.author)
+        ));
+        for category in &entry.categories {
+            xml.push_str(&format!(
+                "      <category>{}</category>\n",
+                escape(category)
+            ));
+        }
+        let description = entry.html.as_ref().unwrap_or(&entry.summary);
+        xml.push_str(&format!(
+            "      <description>{}</description>\n",
+            escape(description)
+        ));
+        xml.push_str("    </item>\n");
+    }
+    xml.push_str("  </channel>\n</rss>\n");
+    xml
+}
+
+/// Write-up, status and links of a project.
+fn project_html(project: &projects::Project) -> String {
+    let mut html = format!("<p>{}</p>\n", escape(project.description));
+    if let Some(write_up) = project.write_up {
+        html.push_str(&format!("<p>{}</p>\n", escape(write_up)));
+    }
+    if let Some(status) = project.status {
+        html.push_str(&format!("<p>Status: {}</p>\n", status.label()));
+    }
+    let mut links = vec![("Repository", project.repository.to_string())];
+    links.extend(project.crates_io_url().map(|url| ("crates.io", url)));
+    for (name, url) in links {
+        html.push_str(&format!(
+            "<p><a href=\"{}\">{}</a></p>\n",
+            escape(&url),
+            name
+        ));
+    }
+    html
+}
+
+/// Plain text of the first paragraph, used as the summary of a post.
+fn first_paragraph(markdown: &str) -> String {
+    let mut text = String::new();
+    for event in Parser::new(markdown) {
+        match event {
+            Event::Text(t) | Event::Code(t) => text.push_str(&t),
+            Event::SoftBreak | Event::HardBreak => text.push(' '),
+            Event::End(TagEnd::Paragraph) => break,
+            // Headings come before the first paragraph, they are not part of it
+            Event::End(TagEnd::Heading(_)) => text.clear(),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Escapes text for XML element content and attribute values.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// `2026-10-19` as `2026-10-19T00:00:00Z`, for Atom.
+fn rfc3339(date: Date) -> String {
+    format!(
+        "{:04}-{:02}-{:02}T00:00:00Z",
+        date.year, date.month, date.day
+    )
+}
+
+/// `2026-10-19` as `Mon, 19 Oct 2026 00:00:00 +0000`, for RSS.
+fn rfc822(date: Date) -> String {
+    const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    format!(
+        "{}, {:02} {} {} 00:00:00 +0000",
+        WEEKDAYS[date.weekday() as usize],
+        date.day,
+        MONTHS[date.month as usize - 1],
+        date.year
+    )
+}
+
+/// Reads the `YYYY-MM-DD` date in `field`, naming the field when it isn't one.
+fn parse_date(value: &str, field: &str) -> Result<Date, String> {
+    Date::parse(value).ok_or_else(|| format!("`{}` is not a YYYY-MM-DD date: {:?}", field, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_covers_markup_and_quotes() {
+        assert_eq!(
+            escape(r#"<a href="x">Tom & Jerry's</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&apos;s&lt;/a&gt;"
+        );
+        assert_eq!(escape("plain ünicode"), "plain ünicode");
+    }
+
+    #[test]
+    fn dates_in_both_feed_formats() {
+        let date = Date::parse("2026-10-19").unwrap();
+        assert_eq!(rfc3339(date), "2026-10-19T00:00:00Z");
+        assert_eq!(rfc822(date), "Mon, 19 Oct 2026 00:00:00 +0000");
+        assert_eq!(rfc3339(EPOCH), "1970-01-01T00:00:00Z");
+        assert_eq!(rfc822(EPOCH), "Thu, 01 Jan 1970 00:00:00 +0000");
+        let leap_day = Date::parse("2024-02-29").unwrap();
+        assert_eq!(rfc822(leap_day), "Thu, 29 Feb 2024 00:00:00 +0000");
+    }
+
+    #[test]
+    fn first_paragraph_skips_headings() {
+        let markdown = "# Title\n\nSome `code`\nand *more*.\n\nSecond paragraph.";
+        assert_eq!(first_paragraph(markdown), "Some code and more.");
+        assert_eq!(first_paragraph("# Only a heading"), "");
+        assert_eq!(first_paragraph(""), "");
+    }
+
+    #[test]
+    fn bad_dates_name_their_field() {
+        assert_eq!(
+            parse_date("2026-02-30", "date").unwrap_err(),
+            "`date` is not a YYYY-MM-DD date: \"2026-02-30\""
+        );
+    }
+}
@@ -0,0 +1,39 @@
+#!/bin/bash
+# Refreshes stats/snapshot.json from the crates.io and GitHub APIs.
+# The build only ever reads the snapshot, so it never needs the network.
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+mkdir -p stats
+
+CRATES=(btc-vanity deloxide)
+REPOSITORIES=(Emivvvvv/btc-vanity Emivvvvv/deloxide Emivvvvv/rlox-ast Emivvvvv/RustyChain Emivvvvv/AmongOS)
+USER_AGENT="emiv-website stats snapshot (https://dev.emiv.online)"
+
+crates="{}"
+for name in "${CRATES[@]}"; do
+  stats=$(curl -fsSL -A "$USER_AGENT" "https://crates.io/api/v1/crates/$name" |
+    jq '{downloads: .crate.downloads, version: .crate.max_stable_version}')
+  crates=$(jq --arg name "$name" --argjson stats "$stats" '.[$name] = $stats' <<<"$crates")
+done
+
+repositories="{}"
+for repository in "${REPOSITORIES[@]}"; do
+  stats=$(curl -fsSL -A "$USER_AGENT" "https://api.github.com/repos/$repository" |
+    jq '{stars: .stargazers_count}')
+  repositories=$(jq --arg name "$repository" --argjson stats "$stats" '.[$name] = $stats' <<<"$repositories")
+done
+
+jq -n --arg date "$(date -u +%F)" --argjson crates "$crates" --argjson repositories "$repositories" \
+  '{fetched_at: $date, crates: $crates, repositories: $repositories}' >stats/snapshot.json
+
+# Daily contributions need an authenticated GraphQL request
+if [[ -n "${GITHUB_TOKEN:-}" ]]; then
+  query='query { user(login: "Emivvvvv") { contributionsCollection { contributionCalendar { weeks { contributionDays { date contributionCount } } } } } }'
+  curl -fsSL -A "$USER_AGENT" -H "Authorization: bearer $GITHUB_TOKEN" \
+    -d "$(jq -n --arg query "$query" '{query: $query}')" https://api.github.com/graphql |
+    jq '[.data.user.contributionsCollection.contributionCalendar.weeks[].contributionDays[]]
+      | {from: .[0].date, counts: map(.contributionCount)}' >stats/contributions.json
+else
+  echo "GITHUB_TOKEN is not set, keeping stats/contributions.json if there is one"
+fi
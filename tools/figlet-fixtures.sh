@@ -0,0 +1,28 @@
+#!/bin/bash
+# Draws the samples of the FIGlet tests in `src/figlet.rs` with the reference
+# `figlet` program, into tests/fixtures/figlet/<font>/<sample>.txt. Run
+# `cargo test figlet` to compare them with our renderer.
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+# Keep in sync with SAMPLES in src/figlet.rs
+SAMPLES=(
+  "banner:EMIV"
+  "pangram:The quick brown fox jumps over the lazy dog"
+  "symbols:/\\[]{}()<>|_"
+)
+
+if ! command -v figlet >/dev/null; then
+  echo "figlet is not installed" >&2
+  exit 1
+fi
+
+for font in fonts/*.flf; do
+  name=$(basename "$font" .flf)
+  mkdir -p "tests/fixtures/figlet/$name"
+  for sample in "${SAMPLES[@]}"; do
+    # A wide output keeps figlet from wrapping the text
+    figlet -d fonts -f "$name" -w 1000 -- "${sample#*:}" >"tests/fixtures/figlet/$name/${sample%%:*}.txt"
+  done
+done
@@ -0,0 +1,153 @@
+//! Reads the Markdown posts in `posts/` and their front-matter block:
+//!
+//! ```text
+//! ---
+//! title: Hello, terminal
+//! date: 2025-06-01
+//! tags: rust, ratatui
+//! ---
+//! ```
+//!
+//! `draft: true` keeps a post off the site and out of the feeds.
+//!
+//! Shared by the build script, which embeds the posts, and the feed generator.
+
+use std::fs;
+use std::path::Path;
+
+#[path = "../src/date/calendar.rs"]
+#[allow(dead_code)] // Only dates are parsed here and in the feed generator
+pub mod calendar;
+
+use calendar::Date;
+
+pub struct FrontMatter {
+    pub title: String,
+    pub date: String, // YYYY-MM-DD
+    pub tags: Vec<String>,
+    pub draft: bool,
+}
+
+pub struct SourcePost {
+    pub slug: String,
+    pub front_matter: FrontMatter,
+    pub markdown: String, // Without the front-matter block
+}
+
+/// Every post in `dir` but the drafts, newest first.
+///
+/// Panics on a missing or malformed front-matter block, so that a broken post
+/// fails the build instead of shipping.
+pub fn read_posts(dir: &Path) -> Vec<SourcePost> {
+    let mut posts = Vec::new();
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries {
+            let path = entry.expect("unreadable entry in posts/").path();
+            if path.extension().is_some_and(|ext| ext == "md") {
+                let post = read_post(&path);
+                if !post.front_matter.draft {
+                    posts.push(post);
+                }
+            }
+        }
+    }
+    // Slug breaks ties so the order is stable
+    posts.sort_by(|a, b| {
+        b.front_matter
+            .date
+            .cmp(&a.front_matter.date)
+            .then_with(|| a.slug.cmp(&b.slug))
+    });
+    posts
+}
+
+fn read_post(path: &Path) -> SourcePost {
+    let source = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    let (front_matter, markdown) = parse_front_matter(&source)
+        .unwrap_or_else(|e| panic!("invalid front-matter in {}: {}", path.display(), e));
+    SourcePost {
+        slug: path.file_stem().unwrap().to_string_lossy().into_owned(),
+        front_matter,
+        markdown,
+    }
+}
+
+fn parse_front_matter(source: &str) -> Result<(FrontMatter, String), String> {
+    let mut lines = source.lines();
+    if lines.next().map(str::trim) != Some("---") {
+        return Err("the file must start with a `---` line".to_string());
+    }
+
+    let mut title = None;
+    let mut date = None;
+    let mut tags = Vec::new();
+    let mut draft = false;
+    let mut closed = false;
+    for (number, line) in lines.by_ref().enumerate() {
+        let line = line.trim();
+        if line == "---" {
+            closed = true;
+            break;
+        }
+        if line.is_empty() {
+            continue;
+        }
+        let (key, value) = line
+            .split_once(':')
+            .ok_or_else(|| format!("line {} is not a `key: value` pair", number + 2))?;
+        let value = value.trim();
+        match key.trim() {
+            "title" if value.is_empty() => return Err("`title` is empty".to_string()),
+            "title" => title = Some(value.trim_matches('"').to_string()),
+            "date" => date = Some(validate_date(value)?),
+            "tags" => {
+                tags = value
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .split(',')
+                    .map(|tag| tag.trim().to_string())
+                    .filter(|tag| !tag.is_empty())
+                    .collect()
+            }
+            "draft" => {
+                draft = match value {
+                    "true" => true,
+                    "false" => false,
+                    _ => return Err(format!("`draft` is `{}`, not true or false", value)),
+                }
+            }
+            other => return Err(format!("unknown key `{}`", other)),
+        }
+    }
+
+    if !closed {
+        return Err("the block is not closed with a `---` line".to_string());
+    }
+    let front_matter = FrontMatter {
+        title: title.ok_or("`title` is missing")?,
+        date: date.ok_or("`date` is missing")?,
+        tags,
+        draft,
+    };
+    let markdown = lines
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_start()
+        .to_string();
+    Ok((front_matter, markdown))
+}
+
+/// Accepts `YYYY-MM-DD` dates that exist on the calendar.
+pub fn validate_date(value: &str) -> Result<String, String> {
+    let invalid = || format!("`{}` is not a YYYY-MM-DD date", value);
+    let parts: Vec<&str> = value.split('-').collect();
+    let [year, month, day] = parts[..] else {
+        return Err(invalid());
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return Err(invalid());
+    }
+    Date::parse(value).ok_or_else(invalid)?;
+    Ok(value.to_string())
+}
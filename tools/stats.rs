@@ -0,0 +1,61 @@
+//! Parses the stats snapshot in `stats/snapshot.json` and the daily
+//! contributions in `stats/contributions.json`, both refreshed by
+//! `tools/fetch-stats.sh`. The snapshot looks like:
+//!
+//! ```text
+//! {
+//!   "fetched_at": "2026-01-31",
+//!   "crates": { "example-crate": { "downloads": 1234, "version": "1.2.3" } },
+//!   "repositories": { "example/example-crate": { "stars": 56 } }
+//! }
+//! ```
+//!
+//! Shared by the build script, which embeds both, and its tests.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::front_matter::validate_date;
+
+/// Numbers of the projects on crates.io and GitHub, the build never touches the network.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Snapshot {
+    pub fetched_at: String,
+    pub crates: BTreeMap<String, CrateStats>, // By crate name
+    pub repositories: BTreeMap<String, RepositoryStats>, // By `owner/name`
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CrateStats {
+    pub downloads: u64,
+    pub version: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RepositoryStats {
+    pub stars: u64,
+}
+
+/// Contributions of every day, starting at `from`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Contributions {
+    pub from: String,
+    pub counts: Vec<u16>,
+}
+
+pub fn parse_snapshot(source: &str) -> Result<Snapshot, String> {
+    let snapshot: Snapshot = serde_json::from_str(source).map_err(|e| e.to_string())?;
+    validate_date(&snapshot.fetched_at).map_err(|e| format!("fetched_at: {}", e))?;
+    Ok(snapshot)
+}
+
+pub fn parse_contributions(source: &str) -> Result<Contributions, String> {
+    let contributions: Contributions = serde_json::from_str(source).map_err(|e| e.to_string())?;
+    validate_date(&contributions.from).map_err(|e| format!("from: {}", e))?;
+    Ok(contributions)
+}